@@ -7,6 +7,9 @@ pub struct LauncherConfig {
     pub venv_dir: PathBuf, // data_dir/venv
     pub uv_path: PathBuf,  // data_dir/uv
     pub version_file: PathBuf, // data_dir/installed_version
+    /// Highest launcher version ever successfully installed. Only ever
+    /// advances — used to refuse downgrade/rollback updates.
+    pub trusted_version_file: PathBuf, // data_dir/trusted_version
     /// The directory containing the installed launcher binary (e.g. `~/.local/bin`).
     /// Stored for Phase 14+ use; not yet read by any hot path.
     #[allow(dead_code)]
@@ -15,6 +18,30 @@ pub struct LauncherConfig {
     pub skip_update: bool, // CONCIERGE_NO_UPDATE_CHECK=1
     pub package_name: String, // "agentic-concierge"
     pub pypi_extra: Option<String>, // CONCIERGE_EXTRA env var (e.g. "mcp,otel")
+    /// Release channel: "stable" (default) or a prerelease identifier such
+    /// as "beta"/"canary" (CONCIERGE_CHANNEL env var). Selects whether
+    /// `update::check_latest_release` looks at `releases/latest` or scans
+    /// `/releases` for the highest matching prerelease.
+    pub channel: String,
+    /// Prefer `uv pip install` over `pip install` when a `uv` binary is
+    /// available (downloaded or on PATH). Defaults to true; set
+    /// `CONCIERGE_USE_UV=0` to force the plain-pip path.
+    pub use_uv: bool,
+    /// Pin the exact interpreter version for the managed venv (e.g.
+    /// "3.11"), from a `--python <ver>` CLI flag or `CONCIERGE_PYTHON_VERSION`.
+    /// `None` keeps `setup`'s existing ">= 3.10" system-Python floor and its
+    /// "3.12" uv/managed-Python default.
+    pub python_version: Option<String>,
+    /// Pre-staged wheel directory for air-gapped installs (CONCIERGE_OFFLINE_WHEELS
+    /// env var). When set, `setup::ensure_environment` installs from this
+    /// directory via `pip install --no-index --find-links` instead of PyPI,
+    /// and builds the venv by hand instead of via the stdlib `venv` module.
+    pub offline_wheels: Option<PathBuf>,
+    /// Pin the expected SHA-256 of the downloaded `uv` archive
+    /// (CONCIERGE_UV_SHA256 env var), for deployments that want to trust an
+    /// exact known-good hash instead of the `.sha256` asset GitHub publishes
+    /// alongside the release.
+    pub uv_sha256: Option<String>,
 }
 
 #[derive(Debug, Error)]
@@ -35,6 +62,7 @@ pub fn launcher_config() -> Result<LauncherConfig, ConfigError> {
     let venv_dir = data_dir.join("venv");
     let uv_path = data_dir.join("uv");
     let version_file = data_dir.join("installed_version");
+    let trusted_version_file = data_dir.join("trusted_version");
 
     let bin_dir = dirs::executable_dir()
         .or_else(|| dirs::home_dir().map(|h| h.join(".local").join("bin")))
@@ -48,19 +76,67 @@ pub fn launcher_config() -> Result<LauncherConfig, ConfigError> {
 
     let pypi_extra = std::env::var("CONCIERGE_EXTRA").ok();
 
+    let channel = std::env::var("CONCIERGE_CHANNEL")
+        .ok()
+        .filter(|v| !v.is_empty())
+        .unwrap_or_else(|| "stable".to_string());
+
+    let use_uv = std::env::var("CONCIERGE_USE_UV")
+        .map(|v| v != "0")
+        .unwrap_or(true);
+
+    let args: Vec<String> = std::env::args().collect();
+    let python_version =
+        parse_python_flag(&args).or_else(|| std::env::var("CONCIERGE_PYTHON_VERSION").ok());
+
+    let offline_wheels = std::env::var("CONCIERGE_OFFLINE_WHEELS")
+        .ok()
+        .map(PathBuf::from);
+
+    let uv_sha256 = std::env::var("CONCIERGE_UV_SHA256").ok();
+
     Ok(LauncherConfig {
         data_dir,
         venv_dir,
         uv_path,
         version_file,
+        trusted_version_file,
         bin_dir,
         installed_bin,
         skip_update,
         package_name: "agentic-concierge".to_string(),
         pypi_extra,
+        channel,
+        use_uv,
+        python_version,
+        offline_wheels,
+        uv_sha256,
     })
 }
 
+/// Return the value following a literal `--python` flag in `args`, if any
+/// (e.g. `--python 3.11` → `Some("3.11")`).
+fn parse_python_flag(args: &[String]) -> Option<String> {
+    args.iter()
+        .position(|a| a == "--python")
+        .and_then(|i| args.get(i + 1).cloned())
+}
+
+/// Remove a literal `--python <value>` pair from `args`, if present.
+/// `exec::exec_python_concierge` uses this so the launcher-only flag
+/// `parse_python_flag` reads isn't also forwarded to the Python concierge's
+/// own CLI.
+pub(crate) fn strip_python_flag(args: &[String]) -> Vec<String> {
+    match args.iter().position(|a| a == "--python") {
+        Some(i) if i + 1 < args.len() => {
+            let mut out = args.to_vec();
+            out.drain(i..=i + 1);
+            out
+        }
+        _ => args.to_vec(),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -118,4 +194,138 @@ mod tests {
         std::env::remove_var("CONCIERGE_DATA_DIR");
         assert_eq!(config.data_dir, PathBuf::from("/tmp/test-override-12345"));
     }
+
+    #[test]
+    fn channel_defaults_to_stable() {
+        let _guard = ENV_LOCK.lock().unwrap();
+        std::env::remove_var("CONCIERGE_CHANNEL");
+        let config = launcher_config().unwrap();
+        assert_eq!(config.channel, "stable");
+    }
+
+    #[test]
+    fn channel_env_override_respected() {
+        let _guard = ENV_LOCK.lock().unwrap();
+        std::env::set_var("CONCIERGE_CHANNEL", "beta");
+        let config = launcher_config().unwrap();
+        std::env::remove_var("CONCIERGE_CHANNEL");
+        assert_eq!(config.channel, "beta");
+    }
+
+    #[test]
+    fn channel_empty_env_falls_back_to_stable() {
+        let _guard = ENV_LOCK.lock().unwrap();
+        std::env::set_var("CONCIERGE_CHANNEL", "");
+        let config = launcher_config().unwrap();
+        std::env::remove_var("CONCIERGE_CHANNEL");
+        assert_eq!(config.channel, "stable");
+    }
+
+    #[test]
+    fn use_uv_defaults_to_true() {
+        let _guard = ENV_LOCK.lock().unwrap();
+        std::env::remove_var("CONCIERGE_USE_UV");
+        let config = launcher_config().unwrap();
+        assert!(config.use_uv);
+    }
+
+    #[test]
+    fn use_uv_disabled_with_zero() {
+        let _guard = ENV_LOCK.lock().unwrap();
+        std::env::set_var("CONCIERGE_USE_UV", "0");
+        let config = launcher_config().unwrap();
+        std::env::remove_var("CONCIERGE_USE_UV");
+        assert!(!config.use_uv);
+    }
+
+    #[test]
+    fn parse_python_flag_finds_value() {
+        let args = vec!["concierge".to_string(), "--python".to_string(), "3.11".to_string()];
+        assert_eq!(parse_python_flag(&args), Some("3.11".to_string()));
+    }
+
+    #[test]
+    fn parse_python_flag_none_when_absent() {
+        let args = vec!["concierge".to_string()];
+        assert_eq!(parse_python_flag(&args), None);
+    }
+
+    #[test]
+    fn parse_python_flag_none_when_flag_is_last_arg() {
+        let args = vec!["concierge".to_string(), "--python".to_string()];
+        assert_eq!(parse_python_flag(&args), None);
+    }
+
+    #[test]
+    fn strip_python_flag_removes_flag_and_value() {
+        let args = vec![
+            "--python".to_string(),
+            "3.11".to_string(),
+            "--other-flag".to_string(),
+        ];
+        assert_eq!(strip_python_flag(&args), vec!["--other-flag".to_string()]);
+    }
+
+    #[test]
+    fn strip_python_flag_noop_when_absent() {
+        let args = vec!["--other-flag".to_string()];
+        assert_eq!(strip_python_flag(&args), args);
+    }
+
+    #[test]
+    fn strip_python_flag_noop_when_flag_is_last_arg() {
+        let args = vec!["--other-flag".to_string(), "--python".to_string()];
+        assert_eq!(strip_python_flag(&args), args);
+    }
+
+    #[test]
+    fn python_version_none_by_default() {
+        let _guard = ENV_LOCK.lock().unwrap();
+        std::env::remove_var("CONCIERGE_PYTHON_VERSION");
+        let config = launcher_config().unwrap();
+        assert_eq!(config.python_version, None);
+    }
+
+    #[test]
+    fn python_version_env_override_respected() {
+        let _guard = ENV_LOCK.lock().unwrap();
+        std::env::set_var("CONCIERGE_PYTHON_VERSION", "3.11");
+        let config = launcher_config().unwrap();
+        std::env::remove_var("CONCIERGE_PYTHON_VERSION");
+        assert_eq!(config.python_version, Some("3.11".to_string()));
+    }
+
+    #[test]
+    fn offline_wheels_none_by_default() {
+        let _guard = ENV_LOCK.lock().unwrap();
+        std::env::remove_var("CONCIERGE_OFFLINE_WHEELS");
+        let config = launcher_config().unwrap();
+        assert_eq!(config.offline_wheels, None);
+    }
+
+    #[test]
+    fn offline_wheels_env_override_respected() {
+        let _guard = ENV_LOCK.lock().unwrap();
+        std::env::set_var("CONCIERGE_OFFLINE_WHEELS", "/tmp/wheels");
+        let config = launcher_config().unwrap();
+        std::env::remove_var("CONCIERGE_OFFLINE_WHEELS");
+        assert_eq!(config.offline_wheels, Some(PathBuf::from("/tmp/wheels")));
+    }
+
+    #[test]
+    fn uv_sha256_none_by_default() {
+        let _guard = ENV_LOCK.lock().unwrap();
+        std::env::remove_var("CONCIERGE_UV_SHA256");
+        let config = launcher_config().unwrap();
+        assert_eq!(config.uv_sha256, None);
+    }
+
+    #[test]
+    fn uv_sha256_env_override_respected() {
+        let _guard = ENV_LOCK.lock().unwrap();
+        std::env::set_var("CONCIERGE_UV_SHA256", "deadbeef");
+        let config = launcher_config().unwrap();
+        std::env::remove_var("CONCIERGE_UV_SHA256");
+        assert_eq!(config.uv_sha256, Some("deadbeef".to_string()));
+    }
 }
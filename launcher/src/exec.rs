@@ -1,10 +1,16 @@
 use std::path::Path;
 
+use crate::config::strip_python_flag;
+
 /// Replace the current process image with the Python concierge binary.
 ///
-/// Strips `--self-update` from args (launcher-only flag). Uses `exec()` so
-/// the Python process inherits the launcher's PID — no zombie, correct signal
-/// forwarding.
+/// Args are passed through unchanged, except launcher-only flags (currently
+/// just `--python <ver>`) are stripped first — `main`'s command dispatcher
+/// only reaches this function once it's established argv isn't one of the
+/// launcher's own subcommands, but `--python` is read out of the same argv
+/// by `config::launcher_config` and has no meaning to the Python concierge's
+/// own CLI. Uses `exec()` so the Python process inherits the launcher's
+/// PID — no zombie, correct signal forwarding.
 ///
 /// This is the Unix (Linux + macOS) implementation.  Both platforms provide
 /// POSIX `execv` via `std::os::unix::process::CommandExt::exec()`, so no
@@ -17,7 +23,7 @@ pub fn exec_python_concierge(concierge_bin: &Path) -> anyhow::Result<()> {
     use std::os::unix::process::CommandExt;
 
     let args: Vec<String> = std::env::args().skip(1).collect();
-    let args: Vec<&String> = args.iter().filter(|a| *a != "--self-update").collect();
+    let args = strip_python_flag(&args);
     let err = std::process::Command::new(concierge_bin).args(&args).exec();
     Err(anyhow::anyhow!("exec failed: {}", err))
 }
@@ -8,20 +8,74 @@ mod update;
 use config::launcher_config;
 use exec::exec_python_concierge;
 use setup::{ensure_environment, upgrade_package};
-use update::{apply_update, check_latest_release, is_newer};
+use update::{
+    apply_update, check_latest_release, confirm_launch, is_newer, launch_previously_failed,
+    record_launch_attempt, rollback,
+};
 
-/// Return true if `--self-update` appears anywhere in the CLI args.
-/// (No `clap` — keeps the binary small.)
-fn parse_launcher_args() -> bool {
-    std::env::args().skip(1).any(|a| a == "--self-update")
+/// Launcher-level commands, dispatched on `argv[1]`. Anything else (including
+/// no args at all) falls through to `Launch` and is passed to the Python
+/// concierge, minus the launcher-only `--python <ver>` pair.
+enum Command {
+    SelfUpdate,
+    Rollback,
+    Version,
+    Env,
+    Launch,
+}
+
+/// Hand-rolled instead of pulling in `clap` — keeps the binary small, and the
+/// command set is short and unlikely to grow much further.
+fn parse_command() -> Command {
+    match std::env::args().nth(1).as_deref() {
+        Some("self-update") => Command::SelfUpdate,
+        Some("rollback") => Command::Rollback,
+        Some("version") => Command::Version,
+        Some("env") => Command::Env,
+        _ => Command::Launch,
+    }
 }
 
 fn main() -> anyhow::Result<()> {
-    let self_update = parse_launcher_args();
+    let command = parse_command();
     let config = launcher_config()?;
 
-    if self_update {
-        // --self-update: always try GitHub regardless of skip_update.
+    match command {
+        Command::Rollback => {
+            rollback(&config)?;
+            eprintln!("[concierge] restart to use the rolled-back version");
+            return Ok(());
+        }
+        Command::Version => {
+            println!("concierge {}", env!("CARGO_PKG_VERSION"));
+            match check_latest_release(&config) {
+                Some(r) if is_newer(&r) => println!("update available: v{}", r.version),
+                Some(_) => println!("up to date"),
+                None => println!("could not reach GitHub to check for updates"),
+            }
+            return Ok(());
+        }
+        Command::Env => {
+            println!("{config:#?}");
+            return Ok(());
+        }
+        Command::SelfUpdate | Command::Launch => {}
+    }
+
+    // Crash-loop guard: if the version we're currently running was just
+    // installed by a self-update and a *previous* run of it already
+    // attempted to start and never reached `confirm_launch`, something
+    // about this build is broken — restore the pre-update binary instead
+    // of repeating the same failure.
+    if launch_previously_failed(&config) {
+        eprintln!("[concierge] this version failed to start on a previous run — rolling back");
+        rollback(&config)?;
+        eprintln!("[concierge] restart to use the previous version");
+        return Ok(());
+    }
+
+    if matches!(command, Command::SelfUpdate) {
+        // self-update: always try GitHub regardless of skip_update.
         match check_latest_release(&config) {
             Some(r) => {
                 apply_update(&config, &r)?;
@@ -39,13 +93,25 @@ fn main() -> anyhow::Result<()> {
         if let Some(r) = check_latest_release(&config) {
             if is_newer(&r) {
                 eprintln!(
-                    "[concierge] update available: v{} \u{2014} run --self-update",
+                    "[concierge] update available: v{} \u{2014} run `concierge self-update`",
                     r.version
                 );
             }
         }
     }
 
+    // Only the path that actually attempts to reach `exec_python_concierge`
+    // records an attempt — self-update exits above before ever trying to
+    // start the new binary, so recording there would leave a stale marker
+    // that the new version's real first launch would wrongly read back as
+    // a previous failure.
+    record_launch_attempt(&config)?;
+
     let concierge_bin = ensure_environment(&config)?;
+
+    // We got this far without crashing, so this build's own startup path
+    // works — clear the unconfirmed flag and any now-unneeded backup.
+    confirm_launch(&config);
+
     exec_python_concierge(&concierge_bin)
 }
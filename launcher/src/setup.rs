@@ -1,4 +1,5 @@
 use std::path::{Path, PathBuf};
+use serde::Deserialize;
 use thiserror::Error;
 
 use crate::config::LauncherConfig;
@@ -13,13 +14,23 @@ pub enum SetupError {
     PackageInstall { code: i32, stderr: String },
     #[error("uv binary is not executable after download")]
     UvNotExecutable,
+    #[error("offline wheel directory does not exist: {0}")]
+    OfflineWheelMissing(PathBuf),
+    #[error("checksum mismatch: expected {expected}, got {actual}")]
+    ChecksumMismatch { expected: String, actual: String },
 }
 
+/// Interpreter version fetched by `fetch_python` when no system Python is
+/// found — matches the `--python 3.12` the uv fallback already requested.
+const MANAGED_PYTHON_VERSION: &str = "3.12";
+
 /// Ensure managed venv exists with agentic-concierge installed.
 /// Returns path to the venv's concierge binary.
 ///
 /// Fast path: if `venv_dir/bin/concierge` already exists, return immediately.
-/// First-time path: detect system Python >= 3.10 or download uv, create venv, pip install.
+/// First-time path: prefer a system Python >= 3.10, then a previously fetched
+/// managed CPython, then fetch one; fall back to `uv venv` only if no
+/// interpreter could be obtained at all.
 pub fn ensure_environment(config: &LauncherConfig) -> anyhow::Result<PathBuf> {
     let concierge_bin = config.venv_dir.join("bin").join("concierge");
     if concierge_bin.exists() {
@@ -29,7 +40,16 @@ pub fn ensure_environment(config: &LauncherConfig) -> anyhow::Result<PathBuf> {
     // First-time setup
     std::fs::create_dir_all(&config.data_dir)?;
 
-    let python = try_system_python();
+    if let Some(wheels_dir) = &config.offline_wheels {
+        return ensure_environment_offline(config, wheels_dir, &concierge_bin);
+    }
+
+    let python_version = config
+        .python_version
+        .as_deref()
+        .unwrap_or(MANAGED_PYTHON_VERSION);
+    let python =
+        try_system_python(config).or_else(|| fetch_python(config, python_version).ok());
 
     if python.is_none() {
         ensure_uv(config).map_err(|e| {
@@ -53,7 +73,7 @@ pub fn ensure_environment(config: &LauncherConfig) -> anyhow::Result<PathBuf> {
         None => {
             // Use uv
             let status = std::process::Command::new(&config.uv_path)
-                .args(["venv", "--python", "3.12"])
+                .args(["venv", "--python", python_version])
                 .arg(&config.venv_dir)
                 .status()
                 .map_err(|e| SetupError::VenvCreation(e.to_string()))?;
@@ -63,14 +83,94 @@ pub fn ensure_environment(config: &LauncherConfig) -> anyhow::Result<PathBuf> {
         }
     }
 
-    // pip install
-    let pip = config.venv_dir.join("bin").join("pip");
-    let package_spec = match &config.pypi_extra {
+    // Install the package — uv if available and enabled, pip otherwise.
+    install_package(config, &package_spec(config))?;
+
+    // Write version file
+    std::fs::write(&config.version_file, env!("CARGO_PKG_VERSION"))?;
+
+    Ok(concierge_bin)
+}
+
+/// Air-gapped counterpart to the network path above: no PyPI, and no
+/// reliance on the stdlib `venv` module, whose `ensurepip` step is the
+/// other thing commonly missing in exactly the locked-down images this
+/// mode targets. Requires a system Python — unlike the network path, this
+/// one doesn't fall back to `fetch_python`, since that needs network too.
+fn ensure_environment_offline(
+    config: &LauncherConfig,
+    wheels_dir: &Path,
+    concierge_bin: &Path,
+) -> anyhow::Result<PathBuf> {
+    if !wheels_dir.is_dir() {
+        return Err(SetupError::OfflineWheelMissing(wheels_dir.to_path_buf()).into());
+    }
+
+    let python = try_system_python(config).ok_or(SetupError::NoPython)?;
+    create_microvenv(config, &python)?;
+    install_offline_package(config, &python, wheels_dir, &package_spec(config))?;
+
+    std::fs::write(&config.version_file, env!("CARGO_PKG_VERSION"))?;
+    Ok(concierge_bin.to_path_buf())
+}
+
+fn package_spec(config: &LauncherConfig) -> String {
+    match &config.pypi_extra {
         Some(extra) => format!("{}[{}]", config.package_name, extra),
         None => config.package_name.clone(),
-    };
-    let output = std::process::Command::new(&pip)
-        .args(["install", "--upgrade", &package_spec])
+    }
+}
+
+/// Upgrade the installed package to a specific version (called after self-update).
+pub fn upgrade_package(config: &LauncherConfig, version: &str) -> anyhow::Result<()> {
+    let package_spec = format!("{}=={}", config.package_name, version);
+    install_package(config, &package_spec)?;
+    std::fs::write(&config.version_file, version)?;
+    Ok(())
+}
+
+/// Read installed package version from version_file; None if file absent.
+///
+/// Not yet called from main — kept as public API for future status/health display.
+#[allow(dead_code)]
+pub fn installed_version(config: &LauncherConfig) -> anyhow::Result<Option<String>> {
+    if !config.version_file.exists() {
+        return Ok(None);
+    }
+    let version = std::fs::read_to_string(&config.version_file)?;
+    Ok(Some(version.trim().to_string()))
+}
+
+// ── Internal helpers ──────────────────────────────────────────────────────────
+
+/// Install `package_spec` into `config.venv_dir` with `uv pip install` when a
+/// `uv` binary is available and `config.use_uv` allows it, falling back to
+/// the venv's own `pip` when uv is absent or disabled — uv resolves and
+/// installs orders of magnitude faster.
+fn install_package(config: &LauncherConfig, package_spec: &str) -> anyhow::Result<()> {
+    if config.use_uv {
+        if let Some(uv) = find_uv(config) {
+            return install_with_uv(&uv, &config.venv_dir, package_spec);
+        }
+    }
+    install_with_pip(&config.venv_dir, package_spec)
+}
+
+/// Locate a usable `uv` binary: the one `ensure_uv` downloads to
+/// `config.uv_path`, or one already on PATH.
+fn find_uv(config: &LauncherConfig) -> Option<PathBuf> {
+    if config.uv_path.exists() {
+        return Some(config.uv_path.clone());
+    }
+    which_bin("uv").ok()
+}
+
+fn install_with_uv(uv_path: &Path, venv_dir: &Path, package_spec: &str) -> anyhow::Result<()> {
+    let python = venv_dir.join("bin").join("python");
+    let output = std::process::Command::new(uv_path)
+        .args(["pip", "install", "--upgrade", "--python"])
+        .arg(&python)
+        .arg(package_spec)
         .output()
         .map_err(|e| SetupError::PackageInstall {
             code: -1,
@@ -81,19 +181,13 @@ pub fn ensure_environment(config: &LauncherConfig) -> anyhow::Result<PathBuf> {
         let stderr = String::from_utf8_lossy(&output.stderr).to_string();
         return Err(SetupError::PackageInstall { code, stderr }.into());
     }
-
-    // Write version file
-    std::fs::write(&config.version_file, env!("CARGO_PKG_VERSION"))?;
-
-    Ok(concierge_bin)
+    Ok(())
 }
 
-/// Upgrade the installed package to a specific version (called after self-update).
-pub fn upgrade_package(config: &LauncherConfig, version: &str) -> anyhow::Result<()> {
-    let pip = config.venv_dir.join("bin").join("pip");
-    let package_spec = format!("{}=={}", config.package_name, version);
+fn install_with_pip(venv_dir: &Path, package_spec: &str) -> anyhow::Result<()> {
+    let pip = venv_dir.join("bin").join("pip");
     let output = std::process::Command::new(&pip)
-        .args(["install", "--upgrade", &package_spec])
+        .args(["install", "--upgrade", package_spec])
         .output()
         .map_err(|e| SetupError::PackageInstall {
             code: -1,
@@ -104,26 +198,17 @@ pub fn upgrade_package(config: &LauncherConfig, version: &str) -> anyhow::Result
         let stderr = String::from_utf8_lossy(&output.stderr).to_string();
         return Err(SetupError::PackageInstall { code, stderr }.into());
     }
-    std::fs::write(&config.version_file, version)?;
     Ok(())
 }
 
-/// Read installed package version from version_file; None if file absent.
+/// Try ["python3", "python"] in PATH.
 ///
-/// Not yet called from main — kept as public API for future status/health display.
-#[allow(dead_code)]
-pub fn installed_version(config: &LauncherConfig) -> anyhow::Result<Option<String>> {
-    if !config.version_file.exists() {
-        return Ok(None);
-    }
-    let version = std::fs::read_to_string(&config.version_file)?;
-    Ok(Some(version.trim().to_string()))
-}
-
-// ── Internal helpers ──────────────────────────────────────────────────────────
-
-/// Try ["python3", "python"] in PATH. Return Some(path) if >= 3.10, else None.
-fn try_system_python() -> Option<PathBuf> {
+/// With `config.python_version` unset, accepts the first interpreter found
+/// that's >= 3.10. With it set (e.g. "3.11"), only an exact major.minor
+/// match is accepted — the floor is a default, not a minimum once a version
+/// is pinned.
+fn try_system_python(config: &LauncherConfig) -> Option<PathBuf> {
+    let pinned = config.python_version.as_deref().and_then(parse_major_minor);
     for name in &["python3", "python"] {
         if let Ok(output) = std::process::Command::new(name).arg("--version").output() {
             if output.status.success() {
@@ -135,7 +220,11 @@ fn try_system_python() -> Option<PathBuf> {
                     &*stderr
                 };
                 if let Some(version) = parse_python_version(version_str) {
-                    if version >= (3, 10) {
+                    let accepted = match pinned {
+                        Some(pinned) => version == pinned,
+                        None => version >= (3, 10),
+                    };
+                    if accepted {
                         if let Ok(path) = which_bin(name) {
                             return Some(path);
                         }
@@ -147,6 +236,13 @@ fn try_system_python() -> Option<PathBuf> {
     None
 }
 
+fn parse_major_minor(s: &str) -> Option<(u32, u32)> {
+    let mut parts = s.trim().splitn(3, '.');
+    let major: u32 = parts.next()?.parse().ok()?;
+    let minor: u32 = parts.next()?.parse().ok()?;
+    Some((major, minor))
+}
+
 fn parse_python_version(s: &str) -> Option<(u32, u32)> {
     let s = s.trim().strip_prefix("Python ")?.trim();
     let mut parts = s.splitn(3, '.');
@@ -155,6 +251,92 @@ fn parse_python_version(s: &str) -> Option<(u32, u32)> {
     Some((major, minor))
 }
 
+/// Write a minimal venv layout by hand — `bin/`, `lib/pythonX.Y/site-packages/`,
+/// and a `pyvenv.cfg` pointing at the base interpreter — instead of shelling
+/// out to `python -m venv`, which depends on the `ensurepip` module that's
+/// often stripped from the same locked-down images offline mode targets.
+/// `install_offline_package`'s `pip install --prefix` then treats
+/// `config.venv_dir` as a normal venv-shaped install prefix, dropping
+/// `bin/concierge` in the spot `ensure_environment`'s fast-path check expects.
+fn create_microvenv(config: &LauncherConfig, python: &Path) -> anyhow::Result<()> {
+    let output = std::process::Command::new(python)
+        .arg("--version")
+        .output()
+        .map_err(|e| SetupError::VenvCreation(e.to_string()))?;
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let version_str = if stdout.contains("Python") {
+        &*stdout
+    } else {
+        &*stderr
+    };
+    let (major, minor) = parse_python_version(version_str).ok_or_else(|| {
+        SetupError::VenvCreation("could not determine Python version".to_string())
+    })?;
+
+    let bin_dir = config.venv_dir.join("bin");
+    let site_packages = config
+        .venv_dir
+        .join("lib")
+        .join(format!("python{major}.{minor}"))
+        .join("site-packages");
+    std::fs::create_dir_all(&bin_dir)?;
+    std::fs::create_dir_all(&site_packages)?;
+
+    let home = python
+        .parent()
+        .map(Path::to_path_buf)
+        .unwrap_or_else(|| PathBuf::from("/usr/bin"));
+    let pyvenv_cfg = format!(
+        "home = {}\ninclude-system-site-packages = false\nversion = {major}.{minor}\n",
+        home.display()
+    );
+    std::fs::write(config.venv_dir.join("pyvenv.cfg"), pyvenv_cfg)?;
+
+    #[cfg(unix)]
+    {
+        let _ = std::fs::remove_file(bin_dir.join("python"));
+        std::os::unix::fs::symlink(python, bin_dir.join("python"))
+            .map_err(|e| SetupError::VenvCreation(e.to_string()))?;
+    }
+    #[cfg(not(unix))]
+    {
+        std::fs::copy(python, bin_dir.join("python.exe"))
+            .map_err(|e| SetupError::VenvCreation(e.to_string()))?;
+    }
+
+    Ok(())
+}
+
+/// Install `package_spec` from a pre-staged wheel directory with no PyPI
+/// access, using the base interpreter's own `pip` (`--prefix` targets the
+/// microvenv layout `create_microvenv` just wrote, same as a real venv's
+/// own pip would).
+fn install_offline_package(
+    config: &LauncherConfig,
+    python: &Path,
+    wheels_dir: &Path,
+    package_spec: &str,
+) -> anyhow::Result<()> {
+    let output = std::process::Command::new(python)
+        .args(["-m", "pip", "install", "--no-index", "--find-links"])
+        .arg(wheels_dir)
+        .arg("--prefix")
+        .arg(&config.venv_dir)
+        .arg(package_spec)
+        .output()
+        .map_err(|e| SetupError::PackageInstall {
+            code: -1,
+            stderr: e.to_string(),
+        })?;
+    if !output.status.success() {
+        let code = output.status.code().unwrap_or(-1);
+        let stderr = String::from_utf8_lossy(&output.stderr).to_string();
+        return Err(SetupError::PackageInstall { code, stderr }.into());
+    }
+    Ok(())
+}
+
 fn which_bin(name: &str) -> anyhow::Result<PathBuf> {
     let output = std::process::Command::new("which").arg(name).output()?;
     if output.status.success() {
@@ -165,20 +347,32 @@ fn which_bin(name: &str) -> anyhow::Result<PathBuf> {
     }
 }
 
+/// uv's release asset naming for the running (os, arch): the platform
+/// suffix in `uv-<suffix>.<ext>`, and the archive format to expect —
+/// `tar.gz` everywhere except Windows, which ships a `.zip`.
+fn uv_release_asset() -> anyhow::Result<(&'static str, &'static str)> {
+    match (std::env::consts::OS, std::env::consts::ARCH) {
+        ("linux", "x86_64") => Ok(("x86_64-unknown-linux-musl", "tar.gz")),
+        ("linux", "aarch64") => Ok(("aarch64-unknown-linux-musl", "tar.gz")),
+        ("macos", "x86_64") => Ok(("x86_64-apple-darwin", "tar.gz")),
+        ("macos", "aarch64") => Ok(("aarch64-apple-darwin", "tar.gz")),
+        ("windows", "x86_64") => Ok(("x86_64-pc-windows-msvc", "zip")),
+        (os, arch) => anyhow::bail!("no uv build available for {os}/{arch}"),
+    }
+}
+
 /// Ensure uv binary exists at config.uv_path. Downloads from GitHub if absent.
 ///
-/// Uses pure-Rust gzip + tar extraction (flate2 + tar crates) — no system
-/// `tar` dependency required.
+/// Uses pure-Rust archive extraction (flate2 + tar, or zip on Windows) — no
+/// system `tar`/`unzip` dependency required.
 fn ensure_uv(config: &LauncherConfig) -> anyhow::Result<()> {
     if config.uv_path.exists() {
         return Ok(());
     }
 
-    let arch = std::env::consts::ARCH;
-    let url = format!(
-        "https://github.com/astral-sh/uv/releases/latest/download/uv-{}-unknown-linux-musl.tar.gz",
-        arch
-    );
+    let (platform, ext) = uv_release_asset()?;
+    let url =
+        format!("https://github.com/astral-sh/uv/releases/latest/download/uv-{platform}.{ext}");
 
     let client = reqwest::blocking::Client::builder()
         .user_agent(format!("concierge-launcher/{}", env!("CARGO_PKG_VERSION")))
@@ -187,13 +381,20 @@ fn ensure_uv(config: &LauncherConfig) -> anyhow::Result<()> {
     let response = client.get(&url).send()?.error_for_status()?;
     let bytes = response.bytes()?;
 
-    // Write tarball to a temp location, then extract with pure-Rust code.
+    verify_uv_checksum(config, &client, &url, &bytes)?;
+
+    // Write the archive to a temp location, then extract with pure-Rust code.
     let extract_dir = config.data_dir.join(".uv-extract");
     std::fs::create_dir_all(&extract_dir)?;
-    let tarball = extract_dir.join("uv.tar.gz");
-    std::fs::write(&tarball, &bytes)?;
+    let archive_path = extract_dir.join(format!("uv.{ext}"));
+    std::fs::write(&archive_path, &bytes)?;
 
-    let uv_bin = extract_uv(&tarball, &extract_dir).inspect_err(|_| {
+    let uv_bin = if ext == "zip" {
+        extract_uv_zip(&archive_path, &extract_dir, "uv.exe")
+    } else {
+        extract_member(&archive_path, &extract_dir, "uv")
+    }
+    .inspect_err(|_| {
         let _ = std::fs::remove_dir_all(&extract_dir);
     })?;
 
@@ -207,22 +408,62 @@ fn ensure_uv(config: &LauncherConfig) -> anyhow::Result<()> {
     Ok(())
 }
 
-/// Extract the `uv` binary from a `.tar.gz` archive using pure Rust.
+/// Verify the downloaded uv archive's SHA-256 before it's extracted or
+/// copied into place — catches a corrupted or tampered download. Uses
+/// `config.uv_sha256` when set (locked-down deployments pinning an exact
+/// known-good hash), otherwise fetches the `.sha256` asset GitHub publishes
+/// alongside the release, reusing the same digest/parsing helpers
+/// `update::verify_checksum` uses for the launcher's own self-update gate.
+fn verify_uv_checksum(
+    config: &LauncherConfig,
+    client: &reqwest::blocking::Client,
+    url: &str,
+    bytes: &[u8],
+) -> anyhow::Result<()> {
+    let expected = match &config.uv_sha256 {
+        Some(pinned) => pinned.to_lowercase(),
+        None => {
+            let checksum_text = client
+                .get(format!("{url}.sha256"))
+                .send()?
+                .error_for_status()?
+                .text()?;
+            crate::update::parse_sha256_line(&checksum_text)
+                .ok_or_else(|| anyhow::anyhow!("malformed or missing uv checksum file"))?
+        }
+    };
+    let actual = crate::update::sha256_hex(bytes);
+    if actual.eq_ignore_ascii_case(&expected) {
+        Ok(())
+    } else {
+        Err(SetupError::ChecksumMismatch { expected, actual }.into())
+    }
+}
+
+/// Extract `member_name` from a `.tar.gz` or `.tar.zst` archive using pure
+/// Rust, dispatching on the archive's extension — gzip via `flate2`, zstd
+/// via the `zstd` crate, both feeding the same `tar::Archive` entry loop.
+/// Shared by `ensure_uv` and any future toolchain download that ships a
+/// single named binary inside a tarball.
 ///
-/// Iterates archive entries; returns the path of the extracted binary on
-/// success, or an error if the archive contains no file named `uv`.
-fn extract_uv(archive_path: &Path, dest_dir: &Path) -> anyhow::Result<PathBuf> {
-    use flate2::read::GzDecoder;
+/// Returns the path of the extracted file, or an error if the archive
+/// contains no entry named `member_name`.
+fn extract_member(archive_path: &Path, dest_dir: &Path, member_name: &str) -> anyhow::Result<PathBuf> {
     use tar::Archive;
 
     let f = std::fs::File::open(archive_path)?;
-    let gz = GzDecoder::new(f);
-    let mut archive = Archive::new(gz);
+    let is_zstd = archive_path.extension().is_some_and(|e| e == "zst");
+    let reader: Box<dyn std::io::Read> = if is_zstd {
+        Box::new(zstd::Decoder::new(f)?)
+    } else {
+        Box::new(flate2::read::GzDecoder::new(f))
+    };
+    let mut archive = Archive::new(reader);
 
     for entry in archive.entries()? {
         let mut entry = entry?;
-        if entry.path()?.file_name().is_some_and(|n| n == "uv") {
-            let out = dest_dir.join("uv");
+        if entry.path()?.file_name().is_some_and(|n| n == member_name) {
+            let out = dest_dir.join(member_name);
             entry.unpack(&out)?;
             #[cfg(unix)]
             {
@@ -232,7 +473,143 @@ fn extract_uv(archive_path: &Path, dest_dir: &Path) -> anyhow::Result<PathBuf> {
             return Ok(out);
         }
     }
-    Err(anyhow::anyhow!("uv binary not found in archive"))
+    Err(anyhow::anyhow!("{member_name} not found in archive"))
+}
+
+/// Extract `member_name` (e.g. `uv.exe`) from a `.zip` archive — the format
+/// uv ships for Windows — using the `zip` crate.
+fn extract_uv_zip(archive_path: &Path, dest_dir: &Path, member_name: &str) -> anyhow::Result<PathBuf> {
+    let f = std::fs::File::open(archive_path)?;
+    let mut archive = zip::ZipArchive::new(f)?;
+
+    for i in 0..archive.len() {
+        let mut entry = archive.by_index(i)?;
+        let matches = entry
+            .enclosed_name()
+            .and_then(|p| p.file_name().map(|n| n.to_os_string()))
+            .is_some_and(|n| n == member_name);
+        if matches {
+            let out = dest_dir.join(member_name);
+            let mut out_file = std::fs::File::create(&out)?;
+            std::io::copy(&mut entry, &mut out_file)?;
+            return Ok(out);
+        }
+    }
+    Err(anyhow::anyhow!("{member_name} not found in archive"))
+}
+
+/// GitHub release metadata for indygreg/python-build-standalone — just
+/// enough to find the `install_only` asset for our platform and version.
+#[derive(Deserialize)]
+struct PythonBuildRelease {
+    assets: Vec<PythonBuildAsset>,
+}
+
+#[derive(Deserialize)]
+struct PythonBuildAsset {
+    name: String,
+    browser_download_url: String,
+}
+
+/// python-build-standalone's platform triple for the running (os, arch).
+fn python_build_triple() -> anyhow::Result<&'static str> {
+    match (std::env::consts::OS, std::env::consts::ARCH) {
+        ("linux", "x86_64") => Ok("x86_64-unknown-linux-gnu"),
+        ("linux", "aarch64") => Ok("aarch64-unknown-linux-gnu"),
+        ("macos", "x86_64") => Ok("x86_64-apple-darwin"),
+        ("macos", "aarch64") => Ok("aarch64-apple-darwin"),
+        (os, arch) => anyhow::bail!("no managed Python build available for {os}/{arch}"),
+    }
+}
+
+/// Download and cache a standalone CPython build from
+/// indygreg/python-build-standalone, returning the path to its `bin/python3`.
+///
+/// Caches under `config.data_dir/pythons/cpython-<version>-<os>-<arch>`; a
+/// version already fetched returns immediately, the same fast path
+/// `concierge_bin.exists()` gives `ensure_environment`.
+fn fetch_python(config: &LauncherConfig, version: &str) -> anyhow::Result<PathBuf> {
+    let triple = python_build_triple()?;
+    let dest_dir = config.data_dir.join("pythons").join(format!(
+        "cpython-{version}-{}-{}",
+        std::env::consts::OS,
+        std::env::consts::ARCH
+    ));
+    let python_bin = dest_dir
+        .join("python")
+        .join("install")
+        .join("bin")
+        .join("python3");
+    if python_bin.exists() {
+        return Ok(python_bin);
+    }
+
+    let client = reqwest::blocking::Client::builder()
+        .user_agent(format!("concierge-launcher/{}", env!("CARGO_PKG_VERSION")))
+        .build()?;
+
+    let release: PythonBuildRelease = client
+        .get("https://api.github.com/repos/indygreg/python-build-standalone/releases/latest")
+        .send()?
+        .error_for_status()?
+        .json()?;
+
+    let prefix = format!("cpython-{version}");
+    let suffix = format!("{triple}-install_only.tar.gz");
+    let asset = release
+        .assets
+        .iter()
+        .find(|a| a.name.starts_with(&prefix) && a.name.ends_with(&suffix))
+        .ok_or_else(|| {
+            anyhow::anyhow!("no python-build-standalone build for {version} ({triple})")
+        })?;
+
+    let bytes = client
+        .get(&asset.browser_download_url)
+        .send()?
+        .error_for_status()?
+        .bytes()?;
+
+    std::fs::create_dir_all(&dest_dir)?;
+    let tarball = dest_dir.join("cpython.tar.gz");
+    std::fs::write(&tarball, &bytes)?;
+
+    extract_archive(&tarball, &dest_dir).inspect_err(|_| {
+        let _ = std::fs::remove_dir_all(&dest_dir);
+    })?;
+    let _ = std::fs::remove_file(&tarball);
+
+    #[cfg(unix)]
+    if python_bin.exists() {
+        use std::os::unix::fs::PermissionsExt;
+        let mut perms = std::fs::metadata(&python_bin)?.permissions();
+        perms.set_mode(0o755);
+        std::fs::set_permissions(&python_bin, perms)?;
+    }
+
+    if !python_bin.exists() {
+        anyhow::bail!(
+            "downloaded python-build-standalone archive did not contain {}",
+            python_bin.display()
+        );
+    }
+
+    Ok(python_bin)
+}
+
+/// Unpack every entry of a `.tar.gz` archive into `dest_dir`, preserving its
+/// internal directory structure — unlike `extract_member`, which pulls out a
+/// single named file, the managed-Python tarball's `bin/python3` is useless
+/// without the `lib/pythonX.Y` and `include/` it ships alongside.
+fn extract_archive(archive_path: &Path, dest_dir: &Path) -> anyhow::Result<()> {
+    use flate2::read::GzDecoder;
+    use tar::Archive;
+
+    let f = std::fs::File::open(archive_path)?;
+    let gz = GzDecoder::new(f);
+    let mut archive = Archive::new(gz);
+    archive.unpack(dest_dir)?;
+    Ok(())
 }
 
 #[cfg(test)]
@@ -246,11 +623,17 @@ mod tests {
             venv_dir: data_dir.join("venv"),
             uv_path: data_dir.join("uv"),
             version_file: data_dir.join("installed_version"),
+            trusted_version_file: data_dir.join("trusted_version"),
             bin_dir: data_dir.join("bin"),
             installed_bin: data_dir.join("bin").join("concierge"),
             skip_update: false,
             package_name: "agentic-concierge".to_string(),
             pypi_extra: None,
+            channel: "stable".to_string(),
+            use_uv: true,
+            python_version: None,
+            offline_wheels: None,
+            uv_sha256: None,
         }
     }
 
@@ -282,7 +665,78 @@ mod tests {
         assert_eq!(result, bin);
     }
 
-    // ── extract_uv tests ──────────────────────────────────────────────────────
+    // ── uv/pip install dispatch tests ─────────────────────────────────────────
+
+    fn write_fake_executable(path: &Path, script: &str) {
+        std::fs::write(path, script).unwrap();
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            std::fs::set_permissions(path, std::fs::Permissions::from_mode(0o755)).unwrap();
+        }
+    }
+
+    #[test]
+    fn find_uv_returns_downloaded_binary_when_present() {
+        let dir = tempdir().unwrap();
+        let config = make_config(dir.path());
+        write_fake_executable(&config.uv_path, "#!/bin/sh\nexit 0\n");
+        assert_eq!(find_uv(&config), Some(config.uv_path.clone()));
+    }
+
+    #[test]
+    fn find_uv_none_when_not_downloaded_and_not_on_path() {
+        let dir = tempdir().unwrap();
+        let config = make_config(dir.path());
+        if which_bin("uv").is_err() {
+            assert!(find_uv(&config).is_none());
+        }
+    }
+
+    #[test]
+    fn install_with_uv_runs_uv_pip_install() {
+        let dir = tempdir().unwrap();
+        let uv_path = dir.path().join("uv");
+        write_fake_executable(&uv_path, "#!/bin/sh\nexit 0\n");
+        let venv_dir = dir.path().join("venv");
+        std::fs::create_dir_all(venv_dir.join("bin")).unwrap();
+        assert!(install_with_uv(&uv_path, &venv_dir, "agentic-concierge").is_ok());
+    }
+
+    #[test]
+    fn install_with_uv_surfaces_failure() {
+        let dir = tempdir().unwrap();
+        let uv_path = dir.path().join("uv");
+        write_fake_executable(&uv_path, "#!/bin/sh\necho boom >&2\nexit 1\n");
+        let venv_dir = dir.path().join("venv");
+        std::fs::create_dir_all(venv_dir.join("bin")).unwrap();
+        let result = install_with_uv(&uv_path, &venv_dir, "agentic-concierge");
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn install_package_prefers_uv_when_present_and_enabled() {
+        let dir = tempdir().unwrap();
+        let config = make_config(dir.path());
+        std::fs::create_dir_all(config.venv_dir.join("bin")).unwrap();
+        write_fake_executable(&config.uv_path, "#!/bin/sh\nexit 0\n");
+        // No `pip` written — if install_package used it instead, this would fail.
+        assert!(install_package(&config, "agentic-concierge").is_ok());
+    }
+
+    #[test]
+    fn install_package_falls_back_to_pip_when_use_uv_disabled() {
+        let dir = tempdir().unwrap();
+        let mut config = make_config(dir.path());
+        config.use_uv = false;
+        std::fs::create_dir_all(config.venv_dir.join("bin")).unwrap();
+        // Would fail if (wrongly) invoked despite use_uv being disabled.
+        write_fake_executable(&config.uv_path, "#!/bin/sh\nexit 1\n");
+        write_fake_executable(&config.venv_dir.join("bin").join("pip"), "#!/bin/sh\nexit 0\n");
+        assert!(install_package(&config, "agentic-concierge").is_ok());
+    }
+
+    // ── extract_member tests ──────────────────────────────────────────────────
 
     /// Build an in-memory .tar.gz containing a single file named `filename`
     /// with `content` as its bytes.
@@ -305,8 +759,26 @@ mod tests {
         enc.finish().unwrap()
     }
 
+    /// Build an in-memory .tar.zst containing a single file named `filename`
+    /// with `content` as its bytes.
+    fn make_tar_zst(filename: &str, content: &[u8]) -> Vec<u8> {
+        use tar::Builder;
+
+        let mut tar_buf = Vec::new();
+        let mut archive = Builder::new(&mut tar_buf);
+        let mut header = tar::Header::new_gnu();
+        header.set_size(content.len() as u64);
+        header.set_mode(0o755);
+        header.set_cksum();
+        archive.append_data(&mut header, filename, content).unwrap();
+        archive.finish().unwrap();
+        drop(archive);
+
+        zstd::encode_all(std::io::Cursor::new(tar_buf), 0).unwrap()
+    }
+
     #[test]
-    fn test_extract_uv_from_synthetic_archive() {
+    fn test_extract_member_from_synthetic_tar_gz() {
         let dir = tempdir().unwrap();
         let fake_uv_content = b"#!/bin/sh\necho uv fake";
 
@@ -314,10 +786,10 @@ mod tests {
         let archive_path = dir.path().join("uv.tar.gz");
         std::fs::write(&archive_path, &tar_gz_bytes).unwrap();
 
-        let result = extract_uv(&archive_path, dir.path());
+        let result = extract_member(&archive_path, dir.path(), "uv");
         assert!(
             result.is_ok(),
-            "extract_uv should succeed: {:?}",
+            "extract_member should succeed: {:?}",
             result.err()
         );
 
@@ -327,7 +799,31 @@ mod tests {
     }
 
     #[test]
-    fn test_extract_uv_missing_binary() {
+    fn test_extract_member_from_synthetic_tar_zst() {
+        let dir = tempdir().unwrap();
+        let fake_python_content = b"fake cpython interpreter";
+
+        let tar_zst_bytes = make_tar_zst("python3", fake_python_content);
+        let archive_path = dir.path().join("cpython.tar.zst");
+        std::fs::write(&archive_path, &tar_zst_bytes).unwrap();
+
+        let result = extract_member(&archive_path, dir.path(), "python3");
+        assert!(
+            result.is_ok(),
+            "extract_member should succeed on a zstd archive: {:?}",
+            result.err()
+        );
+
+        let extracted_path = result.unwrap();
+        assert_eq!(extracted_path, dir.path().join("python3"));
+        assert_eq!(
+            std::fs::read(&extracted_path).unwrap(),
+            fake_python_content
+        );
+    }
+
+    #[test]
+    fn test_extract_member_missing_binary() {
         let dir = tempdir().unwrap();
 
         // Archive contains a file named "not-uv", not "uv"
@@ -335,12 +831,243 @@ mod tests {
         let archive_path = dir.path().join("uv.tar.gz");
         std::fs::write(&archive_path, &tar_gz_bytes).unwrap();
 
-        let result = extract_uv(&archive_path, dir.path());
+        let result = extract_member(&archive_path, dir.path(), "uv");
         assert!(
             result.is_err(),
             "should fail when archive has no 'uv' entry"
         );
         let msg = result.unwrap_err().to_string();
-        assert!(msg.contains("uv binary not found"), "error message: {msg}");
+        assert!(msg.contains("not found in archive"), "error message: {msg}");
+    }
+
+    #[test]
+    fn uv_release_asset_resolves_on_this_platform() {
+        assert!(uv_release_asset().is_ok());
+    }
+
+    /// Build an in-memory .zip containing a single file named `filename`
+    /// with `content` as its bytes.
+    fn make_zip(filename: &str, content: &[u8]) -> Vec<u8> {
+        use std::io::Write;
+        use zip::write::FileOptions;
+
+        let mut buf = Vec::new();
+        let cursor = std::io::Cursor::new(&mut buf);
+        let mut writer = zip::ZipWriter::new(cursor);
+        writer
+            .start_file(filename, FileOptions::<()>::default())
+            .unwrap();
+        writer.write_all(content).unwrap();
+        writer.finish().unwrap();
+        buf
+    }
+
+    #[test]
+    fn test_extract_uv_zip_from_synthetic_archive() {
+        let dir = tempdir().unwrap();
+        let fake_uv_content = b"fake uv.exe content";
+
+        let zip_bytes = make_zip("uv.exe", fake_uv_content);
+        let archive_path = dir.path().join("uv.zip");
+        std::fs::write(&archive_path, &zip_bytes).unwrap();
+
+        let result = extract_uv_zip(&archive_path, dir.path(), "uv.exe");
+        assert!(
+            result.is_ok(),
+            "extract_uv_zip should succeed: {:?}",
+            result.err()
+        );
+
+        let extracted_path = result.unwrap();
+        assert_eq!(extracted_path, dir.path().join("uv.exe"));
+        assert_eq!(std::fs::read(&extracted_path).unwrap(), fake_uv_content);
+    }
+
+    #[test]
+    fn test_extract_uv_zip_missing_binary() {
+        let dir = tempdir().unwrap();
+
+        let zip_bytes = make_zip("not-uv.exe", b"wrong binary");
+        let archive_path = dir.path().join("uv.zip");
+        std::fs::write(&archive_path, &zip_bytes).unwrap();
+
+        let result = extract_uv_zip(&archive_path, dir.path(), "uv.exe");
+        assert!(result.is_err(), "should fail when archive has no uv.exe entry");
+    }
+
+    // ── python version pinning tests ──────────────────────────────────────────
+
+    #[test]
+    fn parse_major_minor_parses_two_part_version() {
+        assert_eq!(parse_major_minor("3.11"), Some((3, 11)));
+    }
+
+    #[test]
+    fn parse_major_minor_ignores_patch_component() {
+        assert_eq!(parse_major_minor("3.11.4"), Some((3, 11)));
+    }
+
+    #[test]
+    fn parse_major_minor_none_on_garbage() {
+        assert_eq!(parse_major_minor("not-a-version"), None);
+    }
+
+    #[test]
+    fn unpinned_python_version_defaults_to_managed_version() {
+        let dir = tempdir().unwrap();
+        let config = make_config(dir.path());
+        assert_eq!(
+            config.python_version.as_deref().unwrap_or(MANAGED_PYTHON_VERSION),
+            MANAGED_PYTHON_VERSION
+        );
+    }
+
+    #[test]
+    fn pinned_python_version_overrides_managed_default() {
+        let dir = tempdir().unwrap();
+        let mut config = make_config(dir.path());
+        config.python_version = Some("3.11".to_string());
+        assert_eq!(
+            config.python_version.as_deref().unwrap_or(MANAGED_PYTHON_VERSION),
+            "3.11"
+        );
+    }
+
+    // ── offline install tests ──────────────────────────────────────────────────
+
+    #[test]
+    fn ensure_environment_offline_fails_fast_when_wheels_dir_missing() {
+        let dir = tempdir().unwrap();
+        let mut config = make_config(dir.path());
+        config.offline_wheels = Some(dir.path().join("no-such-wheels"));
+        let result = ensure_environment(&config);
+        assert!(result.is_err());
+        let msg = result.unwrap_err().to_string();
+        assert!(msg.contains("does not exist"), "error message: {msg}");
+    }
+
+    #[test]
+    fn ensure_environment_offline_fast_path_returns_existing_binary() {
+        let dir = tempdir().unwrap();
+        let mut config = make_config(dir.path());
+        config.offline_wheels = Some(dir.path().join("wheels"));
+        std::fs::create_dir_all(config.venv_dir.join("bin")).unwrap();
+        let bin = config.venv_dir.join("bin").join("concierge");
+        std::fs::write(&bin, "#!/bin/sh\necho fake").unwrap();
+        let result = ensure_environment(&config).unwrap();
+        assert_eq!(result, bin);
+    }
+
+    #[test]
+    fn create_microvenv_writes_pyvenv_cfg_and_bin_python() {
+        let dir = tempdir().unwrap();
+        let config = make_config(dir.path());
+        let fake_python = dir.path().join("system-python3");
+        write_fake_executable(
+            &fake_python,
+            "#!/bin/sh\necho 'Python 3.11.4'\n",
+        );
+
+        create_microvenv(&config, &fake_python).unwrap();
+
+        assert!(config.venv_dir.join("pyvenv.cfg").exists());
+        let site_packages = config
+            .venv_dir
+            .join("lib")
+            .join("python3.11")
+            .join("site-packages");
+        assert!(site_packages.is_dir());
+        #[cfg(unix)]
+        assert!(config.venv_dir.join("bin").join("python").exists());
+    }
+
+    #[test]
+    fn install_offline_package_surfaces_pip_failure() {
+        let dir = tempdir().unwrap();
+        let config = make_config(dir.path());
+        let fake_python = dir.path().join("system-python3");
+        write_fake_executable(&fake_python, "#!/bin/sh\necho boom >&2\nexit 1\n");
+        let wheels_dir = dir.path().join("wheels");
+        std::fs::create_dir_all(&wheels_dir).unwrap();
+
+        let result =
+            install_offline_package(&config, &fake_python, &wheels_dir, "agentic-concierge");
+        assert!(result.is_err());
+    }
+
+    // ── uv checksum verification tests ────────────────────────────────────────
+
+    fn test_client() -> reqwest::blocking::Client {
+        reqwest::blocking::Client::new()
+    }
+
+    #[test]
+    fn verify_uv_checksum_accepts_matching_pinned_hash() {
+        let dir = tempdir().unwrap();
+        let mut config = make_config(dir.path());
+        let content = b"fake uv archive bytes";
+        config.uv_sha256 = Some(crate::update::sha256_hex(content));
+
+        let result = verify_uv_checksum(&config, &test_client(), "https://example.invalid/uv", content);
+        assert!(result.is_ok(), "{:?}", result.err());
+    }
+
+    #[test]
+    fn verify_uv_checksum_rejects_mismatched_pinned_hash() {
+        let dir = tempdir().unwrap();
+        let mut config = make_config(dir.path());
+        config.uv_sha256 = Some(crate::update::sha256_hex(b"other bytes"));
+
+        let result = verify_uv_checksum(
+            &config,
+            &test_client(),
+            "https://example.invalid/uv",
+            b"fake uv archive bytes",
+        );
+        assert!(result.is_err());
+        let msg = result.unwrap_err().to_string();
+        assert!(msg.contains("checksum mismatch"), "error message: {msg}");
+    }
+
+    // ── managed Python toolchain tests ────────────────────────────────────────
+
+    #[test]
+    fn python_build_triple_resolves_on_this_platform() {
+        // Just needs to not error on the CI/dev platforms this launcher targets.
+        assert!(python_build_triple().is_ok());
+    }
+
+    #[test]
+    fn fetch_python_returns_cached_path_without_network() {
+        let dir = tempdir().unwrap();
+        let config = make_config(dir.path());
+        let dest_dir = config.data_dir.join("pythons").join(format!(
+            "cpython-3.12-{}-{}",
+            std::env::consts::OS,
+            std::env::consts::ARCH
+        ));
+        let python_bin = dest_dir
+            .join("python")
+            .join("install")
+            .join("bin")
+            .join("python3");
+        std::fs::create_dir_all(python_bin.parent().unwrap()).unwrap();
+        write_fake_executable(&python_bin, "#!/bin/sh\necho fake\n");
+
+        let result = fetch_python(&config, "3.12").unwrap();
+        assert_eq!(result, python_bin);
+    }
+
+    #[test]
+    fn extract_archive_preserves_directory_structure() {
+        let dir = tempdir().unwrap();
+        let tar_gz_bytes = make_tar_gz("install/bin/python3", b"fake interpreter");
+        let archive_path = dir.path().join("cpython.tar.gz");
+        std::fs::write(&archive_path, &tar_gz_bytes).unwrap();
+
+        extract_archive(&archive_path, dir.path()).unwrap();
+
+        let extracted = dir.path().join("install").join("bin").join("python3");
+        assert_eq!(std::fs::read(&extracted).unwrap(), b"fake interpreter");
     }
 }
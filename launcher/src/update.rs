@@ -1,3 +1,7 @@
+use std::path::PathBuf;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use ed25519_dalek::{Signature, VerifyingKey};
 use semver::Version;
 use serde::Deserialize;
 
@@ -26,13 +30,181 @@ fn asset_target_suffix() -> &'static str {
     }
 }
 
-/// Ed25519 public key for release binary verification.
+/// A single trusted release-signing key, with an optional expiry.
+#[derive(Debug, Clone)]
+struct KeyEntry {
+    key: VerifyingKey,
+    /// Unix timestamp (seconds) after which this key is no longer trusted.
+    /// `None` means the key never expires.
+    valid_until: Option<u64>,
+}
+
+impl KeyEntry {
+    fn is_expired(&self, now: u64) -> bool {
+        self.valid_until.is_some_and(|t| now >= t)
+    }
+}
+
+/// Ring of currently-trusted release-signing keys.
+///
+/// Verification succeeds if *any* non-expired key in the ring validates the
+/// signature. This is what lets a new signing key be rolled out in release N
+/// (shipped in the binary alongside the old one) and the old key retired —
+/// by giving it a `valid_until` — in release N+1, without breaking
+/// launchers that upgraded through N.
+#[derive(Debug, Clone, Default)]
+pub struct Keyring {
+    entries: Vec<KeyEntry>,
+}
+
+impl Keyring {
+    /// Keys embedded in the binary at compile time.
+    ///
+    /// **PLACEHOLDER** — replace with the output of
+    /// `scripts/generate_signing_key.sh` before publishing a release. The
+    /// all-zero key decodes successfully — `VerifyingKey::from_bytes`
+    /// accepts it as a valid (low-order) curve point rather than rejecting
+    /// it, so the ring built from this constant has one entry, not zero.
+    /// Verification still hard-fails regardless: no one holds that point's
+    /// private scalar, so `verify_strict` can never succeed against it —
+    /// the same safe default as the single-key placeholder this replaces,
+    /// just by "no real key can match" rather than "ring is empty".
+    const EMBEDDED: &'static [([u8; 32], Option<u64>)] = &[([0u8; 32], None)];
+
+    fn from_raw(raw: &[([u8; 32], Option<u64>)]) -> Self {
+        let entries = raw
+            .iter()
+            .filter_map(|(bytes, valid_until)| {
+                VerifyingKey::from_bytes(bytes).ok().map(|key| KeyEntry {
+                    key,
+                    valid_until: *valid_until,
+                })
+            })
+            .collect();
+        Keyring { entries }
+    }
+
+    /// Build the keyring from the embedded keys plus any additional keys
+    /// found in `config.data_dir` (see `load_extra_keys`).
+    pub fn load(config: &LauncherConfig) -> Self {
+        let mut ring = Self::from_raw(Self::EMBEDDED);
+        ring.entries.extend(load_extra_keys(config).entries);
+        ring
+    }
+
+    #[cfg(test)]
+    fn from_keys(keys: &[([u8; 32], Option<u64>)]) -> Self {
+        Self::from_raw(keys)
+    }
+
+    fn now_unix() -> u64 {
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or(0)
+    }
+
+    /// Verify `sig` over `binary` against every non-expired key in the ring.
+    /// Returns the first matching key (so callers can log its fingerprint),
+    /// or an error if none match — including the case of an empty ring.
+    fn verify(&self, binary: &[u8], sig: &Signature) -> anyhow::Result<&VerifyingKey> {
+        let now = Self::now_unix();
+        self.entries
+            .iter()
+            .filter(|e| !e.is_expired(now))
+            .find(|e| e.key.verify_strict(binary, sig).is_ok())
+            .map(|e| &e.key)
+            .ok_or_else(|| {
+                anyhow::anyhow!("[concierge] signature verification failed — update aborted")
+            })
+    }
+}
+
+/// Load additional trusted keys from `config.data_dir/trusted_keys`, one per
+/// line: `<64-hex-char pubkey> [valid_until_unix_seconds]`. Missing file or
+/// malformed lines are ignored — this is a best-effort extension of the
+/// embedded ring, not a substitute for it.
+fn load_extra_keys(config: &LauncherConfig) -> Keyring {
+    let path = config.data_dir.join("trusted_keys");
+    let Ok(contents) = std::fs::read_to_string(&path) else {
+        return Keyring::default();
+    };
+
+    let raw: Vec<([u8; 32], Option<u64>)> = contents
+        .lines()
+        .map(str::trim)
+        .filter(|l| !l.is_empty() && !l.starts_with('#'))
+        .filter_map(|line| {
+            let mut parts = line.split_whitespace();
+            let hex = parts.next()?;
+            let valid_until = match parts.next() {
+                Some(v) => Some(v.parse::<u64>().ok()?),
+                None => None,
+            };
+            decode_hex_32(hex).map(|bytes| (bytes, valid_until))
+        })
+        .collect();
+
+    Keyring::from_raw(&raw)
+}
+
+fn decode_hex_32(s: &str) -> Option<[u8; 32]> {
+    if s.len() != 64 {
+        return None;
+    }
+    let mut out = [0u8; 32];
+    for (i, chunk) in s.as_bytes().chunks(2).enumerate() {
+        let byte_str = std::str::from_utf8(chunk).ok()?;
+        out[i] = u8::from_str_radix(byte_str, 16).ok()?;
+    }
+    Some(out)
+}
+
+/// Short hex fingerprint of a verifying key, for logging which key in the
+/// ring matched (not a cryptographic digest — just the first few bytes).
+fn key_fingerprint(key: &VerifyingKey) -> String {
+    key.to_bytes()[..4]
+        .iter()
+        .map(|b| format!("{b:02x}"))
+        .collect()
+}
+
+pub(crate) fn sha256_hex(bytes: &[u8]) -> String {
+    use sha2::{Digest, Sha256};
+    let mut hasher = Sha256::new();
+    hasher.update(bytes);
+    hasher.finalize().iter().map(|b| format!("{b:02x}")).collect()
+}
+
+/// Parse a `.sha256` asset's contents: either a bare 64-char hex digest, or
+/// the traditional `sha256sum` output format `<hex>  <filename>`. Returns
+/// `None` if neither shape matches.
+pub(crate) fn parse_sha256_line(s: &str) -> Option<String> {
+    let token = s.split_whitespace().next()?;
+    (token.len() == 64 && token.bytes().all(|b| b.is_ascii_hexdigit()))
+        .then(|| token.to_lowercase())
+}
+
+/// Verify `bytes` against the checksum recorded in `checksum_text` (the
+/// contents of the downloaded `.sha256` asset).
 ///
-/// **PLACEHOLDER** — replace with output of `scripts/generate_signing_key.sh`
-/// before publishing a release.  Until replaced, `verify_binary_signature`
-/// will always return an error (which is the safe default: unsigned binaries
-/// are rejected).
-const SIGNING_PUBLIC_KEY: [u8; 32] = [0u8; 32];
+/// This is a cheap integrity gate distinct from signature verification: it
+/// catches truncated/corrupt downloads with its own error message, and gives
+/// operators who can't yet sign releases some protection before the
+/// Ed25519 check runs.
+fn verify_checksum(bytes: &[u8], checksum_text: &str) -> anyhow::Result<()> {
+    let expected = parse_sha256_line(checksum_text).ok_or_else(|| {
+        anyhow::anyhow!("[concierge] malformed or missing checksum file — update aborted")
+    })?;
+    let actual = sha256_hex(bytes);
+    if actual.eq_ignore_ascii_case(&expected) {
+        Ok(())
+    } else {
+        anyhow::bail!(
+            "[concierge] checksum mismatch: expected {expected}, got {actual} — update aborted"
+        )
+    }
+}
 
 #[derive(Debug, Clone)]
 pub struct ReleaseInfo {
@@ -52,63 +224,233 @@ struct GitHubAsset {
     browser_download_url: String,
 }
 
-/// Check GitHub Releases API for the latest release.
+/// Does `version`'s prerelease identifier belong to `channel`?
+///
+/// Matches an exact identifier (`beta` matches `-beta`) or a dotted
+/// sub-identifier (`beta` matches `-beta.2`). Stable releases (empty
+/// `Prerelease`) never match a named channel.
+fn channel_matches(version: &Version, channel: &str) -> bool {
+    if version.pre.is_empty() {
+        return false;
+    }
+    let pre = version.pre.as_str();
+    pre == channel || pre.starts_with(&format!("{channel}."))
+}
+
+fn release_asset_url(release: &GitHubRelease) -> Option<&str> {
+    let asset_name = format!("concierge-{}-{}", ARCH_STR, asset_target_suffix());
+    release
+        .assets
+        .iter()
+        .find(|a| a.name == asset_name)
+        .map(|a| a.browser_download_url.as_str())
+}
+
+/// Check GitHub Releases API for the latest release on `config.channel`.
 ///
 /// Returns `None` on *any* failure — network errors are silently ignored so
 /// the launcher never fails due to an unavailable update server.
-pub fn check_latest_release(_config: &LauncherConfig) -> Option<ReleaseInfo> {
-    check_latest_release_inner().ok().flatten()
+pub fn check_latest_release(config: &LauncherConfig) -> Option<ReleaseInfo> {
+    check_latest_release_inner(config).ok().flatten()
 }
 
-fn check_latest_release_inner() -> anyhow::Result<Option<ReleaseInfo>> {
+fn check_latest_release_inner(config: &LauncherConfig) -> anyhow::Result<Option<ReleaseInfo>> {
     let client = reqwest::blocking::Client::builder()
         .user_agent(format!("concierge-launcher/{}", env!("CARGO_PKG_VERSION")))
         .timeout(std::time::Duration::from_secs(5))
         .build()?;
 
+    if config.channel == "stable" {
+        let response = client
+            .get("https://api.github.com/repos/ausmarton/agentic-concierge/releases/latest")
+            .send()?;
+
+        if !response.status().is_success() {
+            return Ok(None);
+        }
+
+        let release: GitHubRelease = response.json()?;
+        let Ok(version) = Version::parse(release.tag_name.trim_start_matches('v')) else {
+            return Ok(None);
+        };
+        // `releases/latest` shouldn't ever surface a prerelease, but the
+        // stable channel must ignore one if it somehow does.
+        if !version.pre.is_empty() {
+            return Ok(None);
+        }
+
+        let download_url = release_asset_url(&release);
+        return Ok(download_url.map(|url| ReleaseInfo {
+            version: version.to_string(),
+            download_url: url.to_string(),
+        }));
+    }
+
+    // Non-stable channel: scan the full release list for the highest
+    // version whose prerelease identifier belongs to this channel.
     let response = client
-        .get("https://api.github.com/repos/ausmarton/agentic-concierge/releases/latest")
+        .get("https://api.github.com/repos/ausmarton/agentic-concierge/releases")
         .send()?;
 
     if !response.status().is_success() {
         return Ok(None);
     }
 
-    let release: GitHubRelease = response.json()?;
-    let version = release.tag_name.trim_start_matches('v').to_string();
-
-    let asset_name = format!("concierge-{}-{}", ARCH_STR, asset_target_suffix());
-    let asset = release.assets.iter().find(|a| a.name == asset_name);
+    let releases: Vec<GitHubRelease> = response.json()?;
+    let best = releases
+        .iter()
+        .filter_map(|r| {
+            let version = Version::parse(r.tag_name.trim_start_matches('v')).ok()?;
+            channel_matches(&version, &config.channel).then_some((version, r))
+        })
+        .max_by(|a, b| a.0.cmp(&b.0));
 
-    Ok(asset.map(|a| ReleaseInfo {
-        version,
-        download_url: a.browser_download_url.clone(),
+    Ok(best.and_then(|(version, release)| {
+        release_asset_url(release).map(|url| ReleaseInfo {
+            version: version.to_string(),
+            download_url: url.to_string(),
+        })
     }))
 }
 
-/// Verify Ed25519 signature of a binary using the embedded public key.
+/// A parsed minisign-format signature file:
 ///
-/// Signature must be exactly 64 raw bytes stored at `sig_path`.
-/// Returns `Err` if the sig is absent, malformed, or does not match.
-fn verify_binary_signature(
-    binary_path: &std::path::Path,
-    sig_path: &std::path::Path,
-) -> anyhow::Result<()> {
-    verify_binary_signature_with_key(binary_path, sig_path, &SIGNING_PUBLIC_KEY)
+/// ```text
+/// untrusted comment: <free-form, unauthenticated>
+/// <base64: "Ed" (2) || key_id (8) || signature (64)>
+/// trusted comment: <free-form, authenticated by global_sig>
+/// <base64: global signature (64), over sig_blob || trusted_comment bytes>
+/// ```
+///
+/// The global signature cryptographically binds the trusted comment (which
+/// can embed `version=`/`file=`) to the primary signature, so a valid
+/// signature for one release asset can't be replayed onto another.
+struct MinisignSignature {
+    /// The 74-byte "Ed" + key_id + signature blob from line 2.
+    sig_blob: Vec<u8>,
+    /// The primary signature over the signed file, i.e. `sig_blob[10..74]`.
+    primary_sig: Signature,
+    /// Text following `trusted comment: ` on line 3.
+    trusted_comment: String,
+    /// Line 4: signature over `sig_blob || trusted_comment.as_bytes()`.
+    global_sig: Signature,
 }
 
-/// Inner verification function that accepts an explicit public key.
-/// Used in tests to avoid dependence on the placeholder `SIGNING_PUBLIC_KEY`.
-fn verify_binary_signature_with_key(
+fn parse_minisign(text: &str) -> anyhow::Result<MinisignSignature> {
+    use base64::Engine;
+
+    let mut lines = text.lines();
+    lines
+        .next()
+        .filter(|l| l.starts_with("untrusted comment:"))
+        .ok_or_else(|| anyhow::anyhow!("malformed minisign signature: missing untrusted comment"))?;
+
+    let sig_blob = base64::engine::general_purpose::STANDARD
+        .decode(lines.next().unwrap_or("").trim())
+        .map_err(|e| anyhow::anyhow!("malformed minisign signature line: {e}"))?;
+    if sig_blob.len() != 74 {
+        anyhow::bail!(
+            "malformed minisign signature: expected a 74-byte blob, got {}",
+            sig_blob.len()
+        );
+    }
+    let primary_sig_bytes: &[u8; 64] = sig_blob[10..].try_into().unwrap();
+    let primary_sig = Signature::from_bytes(primary_sig_bytes);
+
+    let trusted_comment = lines
+        .next()
+        .and_then(|l| l.strip_prefix("trusted comment: "))
+        .ok_or_else(|| anyhow::anyhow!("malformed minisign signature: missing trusted comment"))?
+        .to_string();
+
+    let global_bytes = base64::engine::general_purpose::STANDARD
+        .decode(lines.next().unwrap_or("").trim())
+        .map_err(|e| anyhow::anyhow!("malformed minisign global signature line: {e}"))?;
+    let global_sig_bytes: &[u8; 64] = global_bytes
+        .as_slice()
+        .try_into()
+        .map_err(|_| anyhow::anyhow!("malformed minisign global signature: expected 64 bytes"))?;
+    let global_sig = Signature::from_bytes(global_sig_bytes);
+
+    Ok(MinisignSignature {
+        sig_blob,
+        primary_sig,
+        trusted_comment,
+        global_sig,
+    })
+}
+
+/// Message signed by a minisign global signature: the raw signature blob
+/// followed immediately by the trusted comment's bytes (no separator).
+fn global_signature_message(parsed: &MinisignSignature) -> Vec<u8> {
+    let mut msg = parsed.sig_blob.clone();
+    msg.extend_from_slice(parsed.trusted_comment.as_bytes());
+    msg
+}
+
+impl Keyring {
+    /// Verify a minisign-format signature against every non-expired key in
+    /// the ring: the primary signature must validate the binary, *and* the
+    /// global signature must validate `sig_blob || trusted_comment` under
+    /// the same key. Returns the matching key; the trusted comment stays in
+    /// `parsed`.
+    fn verify_minisign<'a>(
+        &'a self,
+        binary: &[u8],
+        parsed: &MinisignSignature,
+    ) -> anyhow::Result<&'a VerifyingKey> {
+        let now = Self::now_unix();
+        let message = global_signature_message(parsed);
+        self.entries
+            .iter()
+            .filter(|e| !e.is_expired(now))
+            .find(|e| {
+                e.key.verify_strict(binary, &parsed.primary_sig).is_ok()
+                    && e.key.verify_strict(&message, &parsed.global_sig).is_ok()
+            })
+            .map(|e| &e.key)
+            .ok_or_else(|| {
+                anyhow::anyhow!("[concierge] signature verification failed — update aborted")
+            })
+    }
+}
+
+/// Result of a successful `verify_binary_signature` call.
+struct VerifiedSignature<'a> {
+    key: &'a VerifyingKey,
+    /// Set for minisign signatures; `None` for the legacy raw-64-byte format,
+    /// which carries no metadata to bind.
+    trusted_comment: Option<String>,
+}
+
+/// Verify the signature of a binary against every non-expired key in
+/// `keyring`, returning the key that matched (for fingerprint logging) and,
+/// for minisign signatures, the bound trusted comment.
+///
+/// Format is auto-detected: a `sig_path` file starting with
+/// `untrusted comment:` is parsed as minisign; anything else falls back to
+/// the legacy raw 64-byte signature. Returns `Err` if the sig is absent,
+/// malformed, or matches no key in the ring — including an empty ring,
+/// which hard-fails exactly like the single-key placeholder used to.
+fn verify_binary_signature<'a>(
     binary_path: &std::path::Path,
     sig_path: &std::path::Path,
-    pub_key_bytes: &[u8; 32],
-) -> anyhow::Result<()> {
-    use ed25519_dalek::{Signature, VerifyingKey};
-
+    keyring: &'a Keyring,
+) -> anyhow::Result<VerifiedSignature<'a>> {
     let binary = std::fs::read(binary_path)?;
     let sig_bytes = std::fs::read(sig_path)?;
 
+    if sig_bytes.starts_with(b"untrusted comment:") {
+        let text = String::from_utf8(sig_bytes)
+            .map_err(|e| anyhow::anyhow!("signature file is not valid UTF-8: {e}"))?;
+        let parsed = parse_minisign(&text)?;
+        let key = keyring.verify_minisign(&binary, &parsed)?;
+        return Ok(VerifiedSignature {
+            key,
+            trusted_comment: Some(parsed.trusted_comment),
+        });
+    }
+
     let sig_bytes_64: &[u8; 64] = sig_bytes.as_slice().try_into().map_err(|_| {
         anyhow::anyhow!(
             "invalid signature file: expected 64 bytes, got {}",
@@ -116,27 +458,163 @@ fn verify_binary_signature_with_key(
         )
     })?;
     let sig = Signature::from_bytes(sig_bytes_64);
+    let key = keyring.verify(&binary, &sig)?;
+    Ok(VerifiedSignature {
+        key,
+        trusted_comment: None,
+    })
+}
 
-    let key = VerifyingKey::from_bytes(pub_key_bytes)
-        .map_err(|e| anyhow::anyhow!("invalid public key: {e}"))?;
+/// Extract `field=value` from a minisign trusted comment (space-separated
+/// tokens, e.g. `"file=concierge-x86_64 version=0.5.0"`).
+fn trusted_comment_field<'a>(comment: &'a str, field: &str) -> Option<&'a str> {
+    let prefix = format!("{field}=");
+    comment
+        .split_whitespace()
+        .find_map(|tok| tok.strip_prefix(prefix.as_str()))
+}
 
-    key.verify_strict(&binary, &sig)
-        .map_err(|_| anyhow::anyhow!("[concierge] signature verification failed — update aborted"))
+/// Highest version ever successfully installed via `apply_update`, used to
+/// refuse downgrade/rollback updates (e.g. a hijacked GitHub response, or a
+/// stale cached "latest" tag, pointing backwards).
+///
+/// Takes the higher of `trusted_version_file` (this check's own
+/// high-water-mark file) and the legacy `version_file` written by
+/// `setup::ensure_environment`/`upgrade_package`, so a launcher that already
+/// recorded a newer version before this file existed doesn't lose its mark.
+fn trusted_version(config: &LauncherConfig) -> Option<Version> {
+    let read = |path: &std::path::Path| -> Option<Version> {
+        std::fs::read_to_string(path)
+            .ok()
+            .and_then(|s| Version::parse(s.trim()).ok())
+    };
+
+    match (read(&config.trusted_version_file), read(&config.version_file)) {
+        (Some(a), Some(b)) => Some(std::cmp::max(a, b)),
+        (Some(v), None) | (None, Some(v)) => Some(v),
+        (None, None) => None,
+    }
+}
+
+/// Advance the recorded high-water mark to `version`, unless that would be a
+/// regression (the mark only ever moves forward).
+fn record_trusted_version(config: &LauncherConfig, version: &Version) -> anyhow::Result<()> {
+    if let Some(current) = trusted_version(config) {
+        if *version <= current {
+            return Ok(());
+        }
+    }
+    std::fs::write(&config.trusted_version_file, version.to_string())?;
+    Ok(())
+}
+
+/// Path of the previous `installed_bin`, saved by `apply_update` just before
+/// the new one is put in place.
+fn backup_path(config: &LauncherConfig) -> PathBuf {
+    let mut path = config.installed_bin.clone().into_os_string();
+    path.push(".bak");
+    PathBuf::from(path)
+}
+
+/// Atomically restore the backed-up binary over `config.installed_bin`.
+///
+/// Wired to the `rollback` subcommand for manual recovery, and called automatically by
+/// `main` when `launch_previously_failed` reports that the installed
+/// version never reached `confirm_launch`.
+pub fn rollback(config: &LauncherConfig) -> anyhow::Result<()> {
+    let backup = backup_path(config);
+    if !backup.exists() {
+        anyhow::bail!("[concierge] no backup binary available to roll back to");
+    }
+    std::fs::rename(&backup, &config.installed_bin)?;
+    eprintln!("[concierge] rolled back to the previous binary");
+    Ok(())
+}
+
+fn pending_version_file(config: &LauncherConfig) -> PathBuf {
+    config.data_dir.join("pending_version")
+}
+
+/// Marks that some prior run of the currently pending version got far
+/// enough to attempt startup. Distinct from `pending_version_file`, which
+/// is written once by `apply_update` at *install* time, in the old
+/// process — so `pending_version_file` alone matches on the new version's
+/// very first run too, not just on a repeat failure. Holds the version of
+/// the run that made the attempt.
+fn launch_attempt_file(config: &LauncherConfig) -> PathBuf {
+    config.data_dir.join("launch_attempt")
+}
+
+/// True only if the currently running version is still unconfirmed *and*
+/// a previous run of this exact version already recorded an attempt to
+/// start — i.e. this build got as far as attempting startup once already
+/// and never reached `confirm_launch`, so this run is a repeat failure,
+/// not the version's first run ever.
+pub fn launch_previously_failed(config: &LauncherConfig) -> bool {
+    let pending_matches = std::fs::read_to_string(pending_version_file(config))
+        .is_ok_and(|pending| pending.trim() == env!("CARGO_PKG_VERSION"));
+    let attempt_matches = std::fs::read_to_string(launch_attempt_file(config))
+        .is_ok_and(|attempt| attempt.trim() == env!("CARGO_PKG_VERSION"));
+    pending_matches && attempt_matches
+}
+
+/// Record that this run is about to attempt starting the currently
+/// pending version. Must be called only *after* `launch_previously_failed`
+/// has already been checked for this run — check-then-set, so it's a
+/// later run's check that observes this run's attempt, never this run's
+/// own.
+pub fn record_launch_attempt(config: &LauncherConfig) -> anyhow::Result<()> {
+    std::fs::write(launch_attempt_file(config), env!("CARGO_PKG_VERSION"))?;
+    Ok(())
+}
+
+/// Clear the "unconfirmed" flag (and its attempt marker) for the currently
+/// running version, and remove the backup binary kept for it.
+///
+/// `main` calls this once it has reached the point of handing off to the
+/// Python concierge without crashing — i.e. this build's own startup path
+/// works, which is as much as the launcher can verify about itself before
+/// `exec` replaces the process.
+pub fn confirm_launch(config: &LauncherConfig) {
+    let _ = std::fs::remove_file(pending_version_file(config));
+    let _ = std::fs::remove_file(launch_attempt_file(config));
+    let _ = std::fs::remove_file(backup_path(config));
 }
 
 /// Download binary to a temp file, verify Ed25519 signature, chmod +x, then
 /// atomically rename to `config.installed_bin`.
 ///
 /// Flow:
-///   1. Download binary  → data_dir/concierge.new
-///   2. Derive sig_url   → download_url + ".sig"
-///   3. Download sig     → data_dir/concierge.new.sig
-///   4. verify_binary_signature(concierge.new, concierge.new.sig)?
-///   5. chmod 0o755 concierge.new
-///   6. rename(concierge.new, installed_bin)   ← atomic on same filesystem
-///   7. remove concierge.new.sig
-///   8. eprintln! updated message
+///   0. Refuse if release.version is older than the recorded high-water mark
+///      (rollback protection) — checked before any download so a tampered
+///      "latest" response pointing backwards never even starts fetching.
+///   1. Download binary   → data_dir/concierge.new
+///   2. Download checksum → download_url + ".sha256"; verify_checksum(bytes, ..)?
+///   3. Derive sig_url    → download_url + ".sig"
+///   4. Download sig      → data_dir/concierge.new.sig
+///   5. verify_binary_signature(concierge.new, concierge.new.sig)?
+///   6. chmod 0o755 concierge.new
+///   7. copy installed_bin → installed_bin.bak (if installed_bin exists),
+///      so a bad build can be recovered from with `rollback`
+///   8. rename(concierge.new, installed_bin)   ← atomic on same filesystem
+///   9. remove concierge.new.sig
+///  10. record_trusted_version + eprintln! updated message
+///  11. write pending_version, marking the new version unconfirmed — but
+///      NOT yet a prior failure; only a later run that also finds its own
+///      `launch_attempt` marker (written by `record_launch_attempt`, after
+///      this version's first run already attempted startup) counts as that
 pub fn apply_update(config: &LauncherConfig, release: &ReleaseInfo) -> anyhow::Result<()> {
+    // Step 0 — rollback protection
+    let new_version = Version::parse(&release.version)
+        .map_err(|e| anyhow::anyhow!("invalid release version '{}': {e}", release.version))?;
+    if let Some(trusted) = trusted_version(config) {
+        if new_version < trusted {
+            anyhow::bail!(
+                "[concierge] rollback refused: v{new_version} is older than installed v{trusted} — update aborted"
+            );
+        }
+    }
+
     let client = reqwest::blocking::Client::builder()
         .user_agent(format!("concierge-launcher/{}", env!("CARGO_PKG_VERSION")))
         .build()?;
@@ -151,7 +629,26 @@ pub fn apply_update(config: &LauncherConfig, release: &ReleaseInfo) -> anyhow::R
     let new_path = config.data_dir.join("concierge.new");
     std::fs::write(&new_path, &bytes)?;
 
-    // Step 2+3 — download signature
+    // Step 2 — checksum gate, before we even look at the signature
+    let checksum_url = format!("{}.sha256", release.download_url);
+    let checksum_text = client
+        .get(&checksum_url)
+        .send()
+        .and_then(|r| r.error_for_status())
+        .ok()
+        .and_then(|r| r.text().ok());
+    let checksum_result = match &checksum_text {
+        Some(text) => verify_checksum(&bytes, text),
+        None => Err(anyhow::anyhow!(
+            "[concierge] could not fetch checksum file — update aborted"
+        )),
+    };
+    if let Err(e) = checksum_result {
+        let _ = std::fs::remove_file(&new_path);
+        return Err(e);
+    }
+
+    // Step 3+4 — download signature
     let sig_url = format!("{}.sig", release.download_url);
     let sig_response = client.get(&sig_url).send()?.error_for_status()?;
     let sig_bytes = sig_response.bytes()?;
@@ -159,14 +656,35 @@ pub fn apply_update(config: &LauncherConfig, release: &ReleaseInfo) -> anyhow::R
     let sig_path = config.data_dir.join("concierge.new.sig");
     std::fs::write(&sig_path, &sig_bytes)?;
 
-    // Step 4 — verify before applying; clean up on failure
-    if let Err(e) = verify_binary_signature(&new_path, &sig_path) {
-        let _ = std::fs::remove_file(&new_path);
-        let _ = std::fs::remove_file(&sig_path);
-        return Err(e);
+    // Step 5 — verify before applying; clean up on failure
+    let keyring = Keyring::load(config);
+    let verified = match verify_binary_signature(&new_path, &sig_path, &keyring) {
+        Ok(v) => v,
+        Err(e) => {
+            let _ = std::fs::remove_file(&new_path);
+            let _ = std::fs::remove_file(&sig_path);
+            return Err(e);
+        }
+    };
+
+    // A minisign trusted comment binds the signature to the version it was
+    // produced for — reject a mismatch so a valid signature for one release
+    // can't be replayed onto another's binary.
+    if let Some(comment) = &verified.trusted_comment {
+        if let Some(bound_version) = trusted_comment_field(comment, "version") {
+            if bound_version != release.version {
+                let _ = std::fs::remove_file(&new_path);
+                let _ = std::fs::remove_file(&sig_path);
+                anyhow::bail!(
+                    "[concierge] signature is bound to version {bound_version}, not v{} — update aborted",
+                    release.version
+                );
+            }
+        }
     }
+    let matched_key = key_fingerprint(verified.key);
 
-    // Step 5 — chmod +x
+    // Step 6 — chmod +x
     #[cfg(unix)]
     {
         use std::os::unix::fs::PermissionsExt;
@@ -175,17 +693,35 @@ pub fn apply_update(config: &LauncherConfig, release: &ReleaseInfo) -> anyhow::R
         std::fs::set_permissions(&new_path, perms)?;
     }
 
-    // Step 6 — atomic rename
+    // Step 7 — snapshot the current binary so a bad build is recoverable
+    if config.installed_bin.exists() {
+        std::fs::copy(&config.installed_bin, backup_path(config))?;
+    }
+
+    // Step 8 — atomic rename
     std::fs::rename(&new_path, &config.installed_bin)?;
 
-    // Step 7 — remove sig
+    // Step 9 — remove sig
     let _ = std::fs::remove_file(&sig_path);
 
-    eprintln!("[concierge] updated to v{}", release.version);
+    // Step 10 — advance the rollback high-water mark
+    record_trusted_version(config, &new_version)?;
+
+    // Step 11 — mark this version as installed-but-unconfirmed; cleared by
+    // `confirm_launch` once it's been shown to start successfully
+    std::fs::write(pending_version_file(config), new_version.to_string())?;
+
+    eprintln!(
+        "[concierge] updated to v{} (verified with key {matched_key})",
+        release.version
+    );
     Ok(())
 }
 
-/// Return true if `release.version` is strictly greater than the current binary version.
+/// Return true if `release.version` is strictly greater than the current
+/// binary version. Comparison is full semver ordering, including
+/// `Prerelease` — so a `0.4.0-beta.2` build correctly sees `0.4.0-beta.3` as
+/// newer, and later `0.4.0` as newer still.
 pub fn is_newer(release: &ReleaseInfo) -> bool {
     let current = match Version::parse(env!("CARGO_PKG_VERSION")) {
         Ok(v) => v,
@@ -220,11 +756,17 @@ mod tests {
             venv_dir: data_dir.join("venv"),
             uv_path: data_dir.join("uv"),
             version_file: data_dir.join("installed_version"),
+            trusted_version_file: data_dir.join("trusted_version"),
             bin_dir: data_dir.join("bin"),
             installed_bin: data_dir.join("bin").join("concierge"),
             skip_update: false,
             package_name: "agentic-concierge".to_string(),
             pypi_extra: None,
+            channel: "stable".to_string(),
+            use_uv: true,
+            python_version: None,
+            offline_wheels: None,
+            uv_sha256: None,
         }
     }
 
@@ -257,16 +799,269 @@ mod tests {
         assert!(!is_newer(&release));
     }
 
+    #[test]
+    fn semver_prerelease_ordering() {
+        // 0.4.0-beta.3 > 0.4.0-beta.2, and 0.4.0 > 0.4.0-beta.3 — full
+        // semver precedence, not just numeric comparison.
+        assert!(Version::parse("0.4.0-beta.3").unwrap() > Version::parse("0.4.0-beta.2").unwrap());
+        assert!(Version::parse("0.4.0").unwrap() > Version::parse("0.4.0-beta.3").unwrap());
+    }
+
+    // ── Release channel selection tests ───────────────────────────────────────
+
+    #[test]
+    fn channel_matches_exact_identifier() {
+        let v = Version::parse("0.4.0-beta").unwrap();
+        assert!(channel_matches(&v, "beta"));
+    }
+
+    #[test]
+    fn channel_matches_dotted_identifier() {
+        let v = Version::parse("0.4.0-beta.2").unwrap();
+        assert!(channel_matches(&v, "beta"));
+        assert!(!channel_matches(&v, "canary"));
+    }
+
+    #[test]
+    fn channel_matches_rejects_stable_release() {
+        let v = Version::parse("0.4.0").unwrap();
+        assert!(!channel_matches(&v, "beta"));
+    }
+
+    #[test]
+    fn channel_matches_rejects_prefix_collision() {
+        // "betax.1" must not match channel "beta" — dotted-suffix only.
+        let v = Version::parse("0.4.0-betax.1").unwrap();
+        assert!(!channel_matches(&v, "beta"));
+    }
+
     #[test]
     fn arch_str_is_not_unknown() {
         assert_ne!(ARCH_STR, "unknown");
     }
 
+    // ── Rollback protection tests ─────────────────────────────────────────────
+
+    #[test]
+    fn trusted_version_none_when_no_files() {
+        let dir = tempdir().unwrap();
+        let config = make_config(dir.path());
+        assert!(trusted_version(&config).is_none());
+    }
+
+    #[test]
+    fn trusted_version_reads_trusted_version_file() {
+        let dir = tempdir().unwrap();
+        let config = make_config(dir.path());
+        std::fs::write(&config.trusted_version_file, "1.2.3").unwrap();
+        assert_eq!(trusted_version(&config), Some(Version::parse("1.2.3").unwrap()));
+    }
+
+    #[test]
+    fn trusted_version_takes_max_of_both_files() {
+        let dir = tempdir().unwrap();
+        let config = make_config(dir.path());
+        std::fs::write(&config.trusted_version_file, "1.0.0").unwrap();
+        std::fs::write(&config.version_file, "2.0.0").unwrap();
+        assert_eq!(trusted_version(&config), Some(Version::parse("2.0.0").unwrap()));
+    }
+
+    #[test]
+    fn record_trusted_version_advances_mark() {
+        let dir = tempdir().unwrap();
+        let config = make_config(dir.path());
+        record_trusted_version(&config, &Version::parse("1.0.0").unwrap()).unwrap();
+        assert_eq!(trusted_version(&config), Some(Version::parse("1.0.0").unwrap()));
+
+        record_trusted_version(&config, &Version::parse("1.5.0").unwrap()).unwrap();
+        assert_eq!(trusted_version(&config), Some(Version::parse("1.5.0").unwrap()));
+    }
+
+    #[test]
+    fn record_trusted_version_refuses_to_regress() {
+        let dir = tempdir().unwrap();
+        let config = make_config(dir.path());
+        record_trusted_version(&config, &Version::parse("2.0.0").unwrap()).unwrap();
+
+        record_trusted_version(&config, &Version::parse("1.0.0").unwrap()).unwrap();
+        assert_eq!(trusted_version(&config), Some(Version::parse("2.0.0").unwrap()));
+    }
+
+    // ── Backup / rollback / crash-loop guard tests ────────────────────────────
+
+    #[test]
+    fn rollback_fails_with_no_backup() {
+        let dir = tempdir().unwrap();
+        let config = make_config(dir.path());
+        let result = rollback(&config);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn rollback_restores_backup_over_installed_bin() {
+        let dir = tempdir().unwrap();
+        let config = make_config(dir.path());
+        std::fs::create_dir_all(&config.bin_dir).unwrap();
+        std::fs::write(&config.installed_bin, b"new broken build").unwrap();
+        std::fs::write(backup_path(&config), b"old good build").unwrap();
+
+        rollback(&config).unwrap();
+
+        assert_eq!(
+            std::fs::read(&config.installed_bin).unwrap(),
+            b"old good build"
+        );
+        assert!(!backup_path(&config).exists());
+    }
+
+    #[test]
+    fn launch_previously_failed_false_with_no_pending_file() {
+        let dir = tempdir().unwrap();
+        let config = make_config(dir.path());
+        assert!(!launch_previously_failed(&config));
+    }
+
+    #[test]
+    fn launch_previously_failed_false_on_first_run_of_a_newly_installed_version() {
+        // This is exactly the state left behind by a successful self-update:
+        // pending_version matches the now-running build, but nothing has
+        // attempted to start it yet.
+        let dir = tempdir().unwrap();
+        let config = make_config(dir.path());
+        std::fs::write(pending_version_file(&config), env!("CARGO_PKG_VERSION")).unwrap();
+        assert!(!launch_previously_failed(&config));
+    }
+
+    #[test]
+    fn launch_previously_failed_true_when_pending_matches_and_attempt_was_recorded() {
+        let dir = tempdir().unwrap();
+        let config = make_config(dir.path());
+        std::fs::write(pending_version_file(&config), env!("CARGO_PKG_VERSION")).unwrap();
+        record_launch_attempt(&config).unwrap();
+        assert!(launch_previously_failed(&config));
+    }
+
+    #[test]
+    fn launch_previously_failed_false_when_pending_is_a_different_version() {
+        let dir = tempdir().unwrap();
+        let config = make_config(dir.path());
+        std::fs::write(pending_version_file(&config), "0.0.1-not-this-build").unwrap();
+        record_launch_attempt(&config).unwrap();
+        assert!(!launch_previously_failed(&config));
+    }
+
+    #[test]
+    fn self_update_then_first_real_launch_does_not_trigger_rollback() {
+        // Simulates main's actual call sequence across two separate process
+        // runs. Run 1 is `concierge self-update`: apply_update writes
+        // pending_version, then main exits before ever reaching
+        // record_launch_attempt/exec_python_concierge. Run 2 is the plain
+        // `concierge` launch the user runs next — it must not see run 1 as
+        // a prior failure.
+        let dir = tempdir().unwrap();
+        let config = make_config(dir.path());
+
+        // Run 1: self-update — writes pending_version, never records an attempt.
+        std::fs::write(pending_version_file(&config), env!("CARGO_PKG_VERSION")).unwrap();
+
+        // Run 2: normal launch of the version just installed.
+        assert!(!launch_previously_failed(&config));
+        record_launch_attempt(&config).unwrap();
+        confirm_launch(&config);
+        assert!(!launch_previously_failed(&config));
+    }
+
+    #[test]
+    fn a_real_startup_crash_does_trigger_rollback_on_the_next_restart() {
+        // Contrast with the test above: here run 2 actually reaches
+        // record_launch_attempt (i.e. it's a real launch, not self-update)
+        // and then crashes before confirm_launch. Run 3 — the next restart
+        // of the same still-broken version — must roll back.
+        let dir = tempdir().unwrap();
+        let config = make_config(dir.path());
+
+        std::fs::write(pending_version_file(&config), env!("CARGO_PKG_VERSION")).unwrap();
+        assert!(!launch_previously_failed(&config));
+        record_launch_attempt(&config).unwrap();
+        // ... crash here, before confirm_launch ...
+
+        assert!(launch_previously_failed(&config));
+    }
+
+    #[test]
+    fn confirm_launch_clears_pending_version_attempt_and_backup() {
+        let dir = tempdir().unwrap();
+        let config = make_config(dir.path());
+        std::fs::create_dir_all(&config.bin_dir).unwrap();
+        std::fs::write(pending_version_file(&config), env!("CARGO_PKG_VERSION")).unwrap();
+        record_launch_attempt(&config).unwrap();
+        std::fs::write(backup_path(&config), b"old build").unwrap();
+
+        confirm_launch(&config);
+
+        assert!(!pending_version_file(&config).exists());
+        assert!(!launch_attempt_file(&config).exists());
+        assert!(!backup_path(&config).exists());
+        assert!(!launch_previously_failed(&config));
+    }
+
+    #[test]
+    fn confirm_launch_is_a_noop_when_nothing_pending() {
+        let dir = tempdir().unwrap();
+        let config = make_config(dir.path());
+        confirm_launch(&config); // must not panic when the files don't exist
+    }
+
+    // ── Checksum verification tests ───────────────────────────────────────────
+
+    #[test]
+    fn sha256_hex_matches_known_vector() {
+        assert_eq!(
+            sha256_hex(b"abc"),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+    }
+
+    #[test]
+    fn verify_checksum_correct_digest() {
+        let digest = sha256_hex(b"some binary bytes");
+        assert!(verify_checksum(b"some binary bytes", &digest).is_ok());
+    }
+
+    #[test]
+    fn verify_checksum_accepts_sha256sum_format() {
+        let digest = sha256_hex(b"some binary bytes");
+        let line = format!("{digest}  concierge-x86_64-unknown-linux-musl\n");
+        assert!(verify_checksum(b"some binary bytes", &line).is_ok());
+    }
+
+    #[test]
+    fn verify_checksum_wrong_digest() {
+        let digest = sha256_hex(b"different bytes");
+        let result = verify_checksum(b"some binary bytes", &digest);
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("checksum mismatch"));
+    }
+
+    #[test]
+    fn verify_checksum_missing_file() {
+        // An empty/garbage checksum file must be rejected distinctly from a
+        // signature failure. (A `.sha256` asset that can't be fetched at all
+        // never reaches verify_checksum: apply_update aborts on it first.)
+        let result = verify_checksum(b"some binary bytes", "");
+        assert!(result.is_err());
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains("malformed or missing checksum file"));
+    }
+
     // ── Ed25519 signature verification tests ──────────────────────────────────
 
     #[test]
     fn test_verify_signature_valid() {
         let (sk, pk) = make_test_keypair(&TEST_SEED);
+        let keyring = Keyring::from_keys(&[(pk, None)]);
         let dir = tempdir().unwrap();
 
         let binary: &[u8] = b"fake binary content for test";
@@ -277,12 +1072,13 @@ mod tests {
         std::fs::write(&bin_path, binary).unwrap();
         std::fs::write(&sig_path, sig.to_bytes()).unwrap();
 
-        assert!(verify_binary_signature_with_key(&bin_path, &sig_path, &pk).is_ok());
+        assert!(verify_binary_signature(&bin_path, &sig_path, &keyring).is_ok());
     }
 
     #[test]
     fn test_verify_signature_tampered_binary() {
         let (sk, pk) = make_test_keypair(&TEST_SEED);
+        let keyring = Keyring::from_keys(&[(pk, None)]);
         let dir = tempdir().unwrap();
 
         let binary: &[u8] = b"original binary content";
@@ -294,13 +1090,14 @@ mod tests {
         std::fs::write(&bin_path, b"tampered binary content").unwrap();
         std::fs::write(&sig_path, sig.to_bytes()).unwrap();
 
-        assert!(verify_binary_signature_with_key(&bin_path, &sig_path, &pk).is_err());
+        assert!(verify_binary_signature(&bin_path, &sig_path, &keyring).is_err());
     }
 
     #[test]
     fn test_verify_signature_wrong_key() {
         let (sk, _pk) = make_test_keypair(&TEST_SEED);
         let (_sk2, wrong_pk) = make_test_keypair(&TEST_SEED_2);
+        let keyring = Keyring::from_keys(&[(wrong_pk, None)]);
         let dir = tempdir().unwrap();
 
         let binary: &[u8] = b"signed with correct key";
@@ -312,12 +1109,13 @@ mod tests {
         std::fs::write(&sig_path, sig.to_bytes()).unwrap();
 
         // Verify with wrong key — must fail
-        assert!(verify_binary_signature_with_key(&bin_path, &sig_path, &wrong_pk).is_err());
+        assert!(verify_binary_signature(&bin_path, &sig_path, &keyring).is_err());
     }
 
     #[test]
     fn test_verify_signature_truncated_sig() {
         let (_sk, pk) = make_test_keypair(&TEST_SEED);
+        let keyring = Keyring::from_keys(&[(pk, None)]);
         let dir = tempdir().unwrap();
 
         let bin_path = dir.path().join("binary");
@@ -326,7 +1124,187 @@ mod tests {
         // Only 7 bytes — far too short for a 64-byte Ed25519 signature
         std::fs::write(&sig_path, b"short!!").unwrap();
 
-        assert!(verify_binary_signature_with_key(&bin_path, &sig_path, &pk).is_err());
+        assert!(verify_binary_signature(&bin_path, &sig_path, &keyring).is_err());
+    }
+
+    #[test]
+    fn test_verify_signature_second_key_in_ring_matches() {
+        // Signed with the *second* key in the ring — rotation case: the
+        // ring carries both an old and a new key, and the old one matches.
+        let (sk, pk) = make_test_keypair(&TEST_SEED);
+        let (_sk2, other_pk) = make_test_keypair(&TEST_SEED_2);
+        let keyring = Keyring::from_keys(&[(other_pk, None), (pk, None)]);
+        let dir = tempdir().unwrap();
+
+        let binary: &[u8] = b"signed with the second ring key";
+        let sig = sk.sign(binary);
+
+        let bin_path = dir.path().join("binary");
+        let sig_path = dir.path().join("binary.sig");
+        std::fs::write(&bin_path, binary).unwrap();
+        std::fs::write(&sig_path, sig.to_bytes()).unwrap();
+
+        let matched = verify_binary_signature(&bin_path, &sig_path, &keyring).unwrap();
+        assert_eq!(matched.key.to_bytes(), pk);
+        assert!(matched.trusted_comment.is_none());
+    }
+
+    #[test]
+    fn test_verify_signature_expired_key_rejected() {
+        let (sk, pk) = make_test_keypair(&TEST_SEED);
+        // valid_until = 1 (1970-01-01): already expired under any real clock.
+        let keyring = Keyring::from_keys(&[(pk, Some(1))]);
+        let dir = tempdir().unwrap();
+
+        let binary: &[u8] = b"signed with a retired key";
+        let sig = sk.sign(binary);
+
+        let bin_path = dir.path().join("binary");
+        let sig_path = dir.path().join("binary.sig");
+        std::fs::write(&bin_path, binary).unwrap();
+        std::fs::write(&sig_path, sig.to_bytes()).unwrap();
+
+        assert!(verify_binary_signature(&bin_path, &sig_path, &keyring).is_err());
+    }
+
+    #[test]
+    fn test_empty_keyring_hard_fails() {
+        // All-placeholder/expired ring — must fail even with a well-formed
+        // binary and signature, same as the old single placeholder key did.
+        let (sk, _pk) = make_test_keypair(&TEST_SEED);
+        let keyring = Keyring::default();
+        let dir = tempdir().unwrap();
+
+        let binary: &[u8] = b"anything";
+        let sig = sk.sign(binary);
+
+        let bin_path = dir.path().join("binary");
+        let sig_path = dir.path().join("binary.sig");
+        std::fs::write(&bin_path, binary).unwrap();
+        std::fs::write(&sig_path, sig.to_bytes()).unwrap();
+
+        assert!(verify_binary_signature(&bin_path, &sig_path, &keyring).is_err());
+    }
+
+    #[test]
+    fn test_embedded_placeholder_keyring_hard_fails() {
+        // Exercises the real EMBEDDED constant via from_raw, not a
+        // Keyring::default() stand-in — the all-zero key actually decodes,
+        // so this ring has one entry, not zero. Verification must still
+        // hard-fail: no one holds that point's private scalar.
+        let keyring = Keyring::from_raw(Keyring::EMBEDDED);
+        assert_eq!(
+            keyring.entries.len(),
+            1,
+            "the all-zero placeholder key should decode, not be dropped"
+        );
+
+        let (sk, _pk) = make_test_keypair(&TEST_SEED);
+        let binary: &[u8] = b"anything";
+        let sig = sk.sign(binary);
+
+        let dir = tempdir().unwrap();
+        let bin_path = dir.path().join("binary");
+        let sig_path = dir.path().join("binary.sig");
+        std::fs::write(&bin_path, binary).unwrap();
+        std::fs::write(&sig_path, sig.to_bytes()).unwrap();
+
+        assert!(verify_binary_signature(&bin_path, &sig_path, &keyring).is_err());
+    }
+
+    // ── minisign-format signature tests ───────────────────────────────────────
+
+    /// Build a well-formed minisign signature file's contents for `binary`,
+    /// signed by `sk`, with the given trusted comment text.
+    fn make_minisign_sig(sk: &SigningKey, binary: &[u8], trusted_comment: &str) -> String {
+        use base64::Engine;
+
+        let mut sig_blob = Vec::with_capacity(74);
+        sig_blob.extend_from_slice(b"Ed");
+        sig_blob.extend_from_slice(&[0u8; 8]); // key id — unused by our verifier
+        sig_blob.extend_from_slice(&sk.sign(binary).to_bytes());
+
+        let mut global_msg = sig_blob.clone();
+        global_msg.extend_from_slice(trusted_comment.as_bytes());
+        let global_sig = sk.sign(&global_msg);
+
+        format!(
+            "untrusted comment: signature from concierge release key\n{}\ntrusted comment: {}\n{}\n",
+            base64::engine::general_purpose::STANDARD.encode(&sig_blob),
+            trusted_comment,
+            base64::engine::general_purpose::STANDARD.encode(global_sig.to_bytes()),
+        )
+    }
+
+    #[test]
+    fn test_minisign_valid_signature_exposes_trusted_comment() {
+        let (sk, pk) = make_test_keypair(&TEST_SEED);
+        let keyring = Keyring::from_keys(&[(pk, None)]);
+        let dir = tempdir().unwrap();
+
+        let binary: &[u8] = b"minisign-signed release binary";
+        let sig_text = make_minisign_sig(&sk, binary, "file=concierge version=0.5.0");
+
+        let bin_path = dir.path().join("binary");
+        let sig_path = dir.path().join("binary.sig");
+        std::fs::write(&bin_path, binary).unwrap();
+        std::fs::write(&sig_path, sig_text).unwrap();
+
+        let verified = verify_binary_signature(&bin_path, &sig_path, &keyring).unwrap();
+        assert_eq!(verified.key.to_bytes(), pk);
+        assert_eq!(
+            trusted_comment_field(verified.trusted_comment.as_deref().unwrap(), "version"),
+            Some("0.5.0")
+        );
+    }
+
+    #[test]
+    fn test_minisign_tampered_binary_rejected() {
+        let (sk, pk) = make_test_keypair(&TEST_SEED);
+        let keyring = Keyring::from_keys(&[(pk, None)]);
+        let dir = tempdir().unwrap();
+
+        let binary: &[u8] = b"original binary";
+        let sig_text = make_minisign_sig(&sk, binary, "version=0.5.0");
+
+        let bin_path = dir.path().join("binary");
+        let sig_path = dir.path().join("binary.sig");
+        std::fs::write(&bin_path, b"tampered binary").unwrap();
+        std::fs::write(&sig_path, sig_text).unwrap();
+
+        assert!(verify_binary_signature(&bin_path, &sig_path, &keyring).is_err());
+    }
+
+    #[test]
+    fn test_minisign_replayed_signature_onto_different_comment_rejected() {
+        // The primary signature still validates the (unchanged) binary, but
+        // the global signature was produced over a different trusted
+        // comment, so re-attaching it to a new comment must fail.
+        let (sk, pk) = make_test_keypair(&TEST_SEED);
+        let keyring = Keyring::from_keys(&[(pk, None)]);
+        let dir = tempdir().unwrap();
+
+        let binary: &[u8] = b"asset shared across releases";
+        let mut sig_text = make_minisign_sig(&sk, binary, "version=0.5.0");
+        sig_text = sig_text.replace("version=0.5.0", "version=9.9.9");
+
+        let bin_path = dir.path().join("binary");
+        let sig_path = dir.path().join("binary.sig");
+        std::fs::write(&bin_path, binary).unwrap();
+        std::fs::write(&sig_path, sig_text).unwrap();
+
+        assert!(verify_binary_signature(&bin_path, &sig_path, &keyring).is_err());
+    }
+
+    #[test]
+    fn trusted_comment_field_extracts_version_and_file() {
+        let comment = "file=concierge-x86_64-unknown-linux-musl version=0.5.0";
+        assert_eq!(trusted_comment_field(comment, "version"), Some("0.5.0"));
+        assert_eq!(
+            trusted_comment_field(comment, "file"),
+            Some("concierge-x86_64-unknown-linux-musl")
+        );
+        assert_eq!(trusted_comment_field(comment, "missing"), None);
     }
 
     #[test]
@@ -344,9 +1322,11 @@ mod tests {
         // Bad sig: not 64 bytes — Signature::from_bytes will reject it
         std::fs::write(&sig_path, b"bad!").unwrap();
 
-        // verify_binary_signature uses SIGNING_PUBLIC_KEY; even with the
-        // placeholder key the sig parsing fails first (wrong length).
-        let result = verify_binary_signature(&new_path, &sig_path);
+        // The embedded keyring is the all-zero placeholder (one entry that
+        // no real signature can match); even with a real key loaded the sig
+        // parsing fails first (wrong length).
+        let keyring = Keyring::load(&config);
+        let result = verify_binary_signature(&new_path, &sig_path, &keyring);
         assert!(result.is_err(), "bad sig should be rejected");
 
         // The installed binary must NOT have been written